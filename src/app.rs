@@ -1,11 +1,110 @@
 use crate::config::Config;
-use crate::models::{InputMode, LogEntry};
+use crate::daemon;
+use crate::models::{
+    ActivityMetric, AppState, Checkin, CheckinStep, DayActivity, FocusPane, HourMetric, HourStats,
+    InputMode, Interruption, LogEntry, MoodStats, NamedTimer, PomodoroEvent, PomodoroPhase,
+    PomodoroStats, Reminder, Streak, TagNode, TodoCycleStats, build_tag_tree,
+};
 use crate::storage;
 use crate::ui::parser;
-use chrono::{DateTime, Local};
+use chrono::{
+    DateTime, Datelike, Local, Months, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Timelike,
+};
 use ratatui::widgets::ListState;
-use std::collections::HashMap;
-use tui_textarea::TextArea;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use tui_textarea::{CursorMove, TextArea};
+
+/// 로그의 뽀모도로 기록을 재생하며 복원 중인 타이머 상태입니다.
+struct PomodoroReplay {
+    phase: PomodoroPhase,
+    tags: Vec<String>,
+    end: NaiveDateTime,
+    paused_at: Option<NaiveDateTime>,
+    interruptions: Vec<Interruption>,
+}
+
+/// `date` 날짜 로그의 뽀모도로 기록을 차례로 재생하여, 마지막까지 끝나지 않은 타이머를 찾습니다.
+fn replay_pomodoro(entries: &[LogEntry], date: NaiveDate) -> Option<PomodoroReplay> {
+    let mut replay: Option<PomodoroReplay> = None;
+    let mut interruptions = Vec::new();
+
+    for entry in entries {
+        if let Some(interruption) = parser::parse_interruption_entry(&entry.content) {
+            interruptions.push(interruption);
+            continue;
+        }
+        let Some(event) = parser::parse_pomodoro_entry(&entry.content) else {
+            continue;
+        };
+        let Some(time) = parser::parse_entry_time(&entry.content, date) else {
+            continue;
+        };
+        match event {
+            PomodoroEvent::Started {
+                phase,
+                minutes,
+                tags,
+            } => {
+                interruptions.clear();
+                replay = Some(PomodoroReplay {
+                    phase,
+                    tags,
+                    end: time + chrono::Duration::minutes(minutes),
+                    paused_at: None,
+                    interruptions: Vec::new(),
+                });
+            }
+            PomodoroEvent::Completed { .. } | PomodoroEvent::Stopped => replay = None,
+            PomodoroEvent::Paused => {
+                if let Some(r) = &mut replay {
+                    r.paused_at = Some(time);
+                }
+            }
+            PomodoroEvent::Resumed => {
+                if let Some(r) = &mut replay
+                    && let Some(paused_at) = r.paused_at.take()
+                {
+                    r.end += time - paused_at;
+                }
+            }
+            PomodoroEvent::Extended { minutes } => {
+                if let Some(r) = &mut replay {
+                    r.end += chrono::Duration::minutes(minutes);
+                }
+            }
+        }
+    }
+
+    let mut replay = replay?;
+    replay.interruptions = interruptions;
+    Some(replay)
+}
+
+/// 로그 항목들에서 끝까지 마친 집중 세션의 수를 셉니다. (앱 시작 시 오늘 로그 기준)
+fn count_completed_work_sessions(entries: &[LogEntry]) -> usize {
+    let mut phase = None;
+    let mut count = 0;
+    for entry in entries {
+        match parser::parse_pomodoro_entry(&entry.content) {
+            Some(PomodoroEvent::Started { phase: p, .. }) => phase = Some(p),
+            Some(PomodoroEvent::Completed { .. }) => {
+                count += usize::from(phase.take() == Some(PomodoroPhase::Work));
+            }
+            Some(PomodoroEvent::Stopped) => phase = None,
+            _ => {}
+        }
+    }
+    count
+}
+
+/// 체크인 시각(`HH:MM`) 중 `now` 이전의 가장 늦은 시각을 반환합니다. 잘못된 시각은 무시합니다.
+fn checkin_slot_before(times: &[String], now: NaiveTime) -> Option<NaiveTime> {
+    times
+        .iter()
+        .filter_map(|time| NaiveTime::parse_from_str(time.trim(), "%H:%M").ok())
+        .filter(|time| *time <= now)
+        .max()
+}
 
 /// 애플리케이션의 전체 상태를 관리하는 구조체입니다.
 /// UI 렌더링에 필요한 모든 데이터와 상태(입력 모드, 로그 목록, 팝업 상태 등)를 포함합니다.
@@ -14,29 +113,70 @@ pub struct App<'a> {
     pub textarea: TextArea<'a>,
     pub logs: Vec<LogEntry>,
     pub logs_state: ListState,
-    pub show_mood_popup: bool,
+    pub focus: FocusPane,
+    pub tasks_state: ListState, // 오늘의 할 일 패널 커서
+    pub show_mood_popup: bool,  // 기분/에너지/집중도 체크인 팝업
+    pub checkin_step: CheckinStep,
+    pub checkin_energy: u8,
+    pub checkin_focus: u8,
+    pub checkin_startup: bool, // 앱 시작 시 뜬 체크인이면 끝난 뒤 할 일 이월을 확인
+    pub checkin_prompted_slot: Option<NaiveTime>, // 이미 물어본 예약 체크인 시각
     pub mood_list_state: ListState,
     pub show_todo_popup: bool, // 할 일 요약 팝업
     pub pending_todos: Vec<String>,
     pub todo_list_state: ListState,
     pub show_tag_popup: bool,
-    pub tags: Vec<(String, usize)>, // (태그명, 횟수)
-    pub tag_list_state: ListState,
+    pub tag_tree: Vec<TagNode>,          // 계층 태그 트리 (깊이 우선 순서)
+    pub collapsed_tags: HashSet<String>, // 접어 둔 태그 경로 (세션 동안 유지)
+    pub tag_list_state: ListState,       // 펼쳐진 태그 중 선택 위치
     pub is_search_result: bool,
+    pub viewing_date: Option<NaiveDate>, // 지난 날짜를 보는 중이면 그 날짜
+    pub known_tags: Vec<(String, usize)>, // 태그 자동완성 후보 (시작 시 읽고, 저장할 때마다 갱신)
+    pub tag_suggestions: Vec<String>,
+    pub tag_suggestion_index: usize,
     pub should_quit: bool,
 
     // 로컬 파워 기능
     pub pomodoro_end: Option<DateTime<Local>>,
+    pub pomodoro_tags: Vec<String>, // 진행 중인(마지막) 집중 세션에 붙은 태그
+    pub pomodoro_phase: PomodoroPhase,
+    pub pomodoro_next: Option<PomodoroPhase>, // 시작 확인을 기다리는 다음 단계
+    pub pomodoro_session: usize,              // 현재(또는 직전) 집중 세션의 오늘 순번
+    pomodoro_completed: (NaiveDate, usize),   // (날짜, 그날 끝까지 마친 집중 세션 수)
+    pub pomodoro_paused: Option<chrono::Duration>, // 일시정지 중이면 남은 시간
+    pub show_pomodoro_cancel_popup: bool,
+    pub pomodoro_interruptions: Vec<Interruption>, // 현재 세션 중 기록한 방해
+    pub parking_lot: Vec<Interruption>,            // 휴식 때 검토할 방해 목록
+    pub parking_lot_state: ListState,
+    pub show_parking_lot_popup: bool,
+    pub show_interruption_popup: bool,
+    pub interruption_input: String,
+    pub interruption_external: bool, // 입력 중인 방해가 외부(호출 등)인지
     pub show_activity_popup: bool,
-    pub activity_data: HashMap<String, usize>, // "YYYY-MM-DD" -> line_count
+    pub activity_data: BTreeMap<NaiveDate, DayActivity>,
+    pub activity_metric: ActivityMetric, // 히트맵에 표시할 지표
+    pub state: AppState,                 // 이정표/축하 기록 (state.toml)
+    pub celebration: Option<String>,     // 축하 팝업 메시지
+    pub log_streak: Streak,              // 로그를 남긴 연속 일수
+    pub todo_streak: Streak,             // 할 일을 하나 이상 완료한 연속 일수
+    pub activity_cursor: NaiveDate,      // 활동 히트맵에서 선택한 날짜
+    pub todo_cycle_stats: TodoCycleStats,
+    pub show_pomodoro_stats_popup: bool,
+    pub pomodoro_stats: PomodoroStats,
+    pub show_mood_trend_popup: bool,
+    pub show_hour_popup: bool,
+    pub hour_stats: HourStats,
+    pub hour_metric: HourMetric,
+    pub hour_tag_filter: String, // 시간대 분포를 볼 태그 (비우면 전체)
+    pub mood_stats: MoodStats,
     pub show_path_popup: bool,
 
     // 뽀모도로 입력 팝업
     pub show_pomodoro_popup: bool,
     pub pomodoro_input: String,
 
-    // 뽀모도로 종료 알림 (이 시간까지 알림 표시 & 입력 차단)
-    // 뽀모도로 종료 알림 (이 시간까지 알림 표시 & 입력 차단)
+    // 뽀모도로 종료 알림 (이 시간까지 입력 차단, 이후 아무 키나 눌러 닫기)
+    pub show_siren_popup: bool,
     pub pomodoro_alert_expiry: Option<DateTime<Local>>,
 
     // 설정 (안내 문구 등)
@@ -44,27 +184,72 @@ pub struct App<'a> {
     pub config: Config,
     pub path_list_state: ListState,
     pub notification: Option<(String, DateTime<Local>)>,
+
+    // 할 일 시각 알림 (remind:)
+    pub reminders: Vec<Reminder>,          // 아직 울리지 않은 알림
+    pub active_reminder: Option<Reminder>, // 현재 화면에 떠 있는 알림
+    pub snoozed_reminders: HashMap<(String, usize), NaiveDateTime>, // (파일, 라인) -> 미뤄진 시각
+
+    // 데몬에 붙여 둔 이 앱의 터미널. 앱이 떠 있는 동안은 데몬이 건너뛰고 앱이 직접 울림
+    daemon_tty: Option<String>,
+
+    // 지금 집중하고 있는 할 일 (내용)
+    pub current_focus: Option<String>,
+
+    // 할 일에 묶인 작업 타이머: (할 일 내용, 시작 시각). 멈추면 `spent:`에 누적됨
+    pub todo_timer: Option<(String, DateTime<Local>)>,
+
+    // 이름 붙은 타이머/스톱워치 (여러 개 동시 실행)
+    pub timers: Vec<NamedTimer>,
+    pub show_timers_popup: bool,
+    pub timers_state: ListState,
+    pub timer_input: String,
+
+    // 시간 감각 알림 (주기 알림 / 과몰입 경고)
+    pub activity_started: DateTime<Local>, // 쉬지 않고 이어진 활동의 시작 시각
+    pub last_activity: DateTime<Local>,    // 마지막 키 입력 시각
+    pub last_chime: DateTime<Local>,
+    pub hyperfocus_nudged_at: Option<DateTime<Local>>,
 }
 
+/// 축하할 연속 기록 이정표 (일)
+const STREAK_MILESTONES: [usize; 8] = [3, 7, 14, 30, 50, 100, 200, 365];
+
 impl<'a> App<'a> {
     /// `App`의 새로운 인스턴스를 생성하고 초기화합니다.
     /// 설정 파일을 로드하고, 오늘 날짜의 로그를 읽어옵니다.
     pub fn new() -> App<'a> {
-        let config = Config::load();
+        Self::with_config(Config::load())
+    }
 
+    /// 주어진 설정으로 `App`을 만듭니다. 로그는 `config.data.log_path`에서 읽습니다.
+    fn with_config(config: Config) -> App<'a> {
         let mut textarea = TextArea::default();
         textarea.set_placeholder_text(&config.placeholders.editing);
 
+        // 오늘에 해당하는 반복 할 일을 먼저 채워 넣음 (하루 한 번)
+        let _ = storage::insert_recurring_todos(
+            &config.data.log_path,
+            &config.recurring.tasks,
+            Local::now().date_naive(),
+        );
+
         let logs =
             storage::read_today_entries(&config.data.log_path).unwrap_or_else(|_| Vec::new());
         let mut logs_state = ListState::default();
         if !logs.is_empty() {
             logs_state.select(Some(logs.len() - 1));
         }
+        let pomodoro_completed = (
+            Local::now().date_naive(),
+            count_completed_work_sessions(&logs),
+        );
 
         // 이미 기분 로그가 있는지 확인
-        let has_mood = logs.iter().any(|log| log.content.contains("Mood: "));
-        let show_mood_popup = !has_mood;
+        let has_mood = logs
+            .iter()
+            .any(|log| parser::parse_checkin(&log.content).is_some());
+        let show_mood_popup = !has_mood && !config.moods.is_empty();
 
         let mut mood_list_state = ListState::default();
         if show_mood_popup {
@@ -92,42 +277,1259 @@ impl<'a> App<'a> {
 
         let input_mode = InputMode::Editing;
 
-        App {
+        let mut app = App {
             input_mode,
             textarea,
             logs,
             logs_state,
+            focus: FocusPane::Logs,
+            tasks_state: ListState::default(),
             show_mood_popup,
+            checkin_step: CheckinStep::Mood,
+            checkin_energy: 3,
+            checkin_focus: 3,
+            checkin_startup: show_mood_popup,
+            checkin_prompted_slot: None,
             mood_list_state,
             show_todo_popup,
             pending_todos,
             todo_list_state: ListState::default(),
             show_tag_popup: false,
-            tags: Vec::new(),
+            tag_tree: Vec::new(),
+            collapsed_tags: HashSet::new(),
             tag_list_state: ListState::default(),
             is_search_result: false,
+            viewing_date: None,
+            known_tags: storage::get_all_tags(&config.data.log_path).unwrap_or_default(),
+            tag_suggestions: Vec::new(),
+            tag_suggestion_index: 0,
             should_quit: false,
             pomodoro_end: None,
+            pomodoro_tags: Vec::new(),
+            pomodoro_phase: PomodoroPhase::Work,
+            pomodoro_next: None,
+            pomodoro_session: 0,
+            pomodoro_completed,
+            pomodoro_paused: None,
+            show_pomodoro_cancel_popup: false,
+            pomodoro_interruptions: Vec::new(),
+            parking_lot: Vec::new(),
+            parking_lot_state: ListState::default(),
+            show_parking_lot_popup: false,
+            show_interruption_popup: false,
+            interruption_input: String::new(),
+            interruption_external: false,
             show_activity_popup: false,
-            activity_data: HashMap::new(),
+            show_pomodoro_stats_popup: false,
+            pomodoro_stats: PomodoroStats::default(),
+            show_mood_trend_popup: false,
+            show_hour_popup: false,
+            hour_stats: HourStats::default(),
+            hour_metric: HourMetric::default(),
+            hour_tag_filter: String::new(),
+            mood_stats: MoodStats::default(),
+            activity_data: storage::get_activity_stats(&config.data.log_path).unwrap_or_default(),
+            activity_metric: ActivityMetric::default(),
+            activity_cursor: Local::now().date_naive(),
+            state: storage::load_state(&config.data.log_path),
+            celebration: None,
+            log_streak: Streak::default(),
+            todo_streak: Streak::default(),
+            todo_cycle_stats: TodoCycleStats::default(),
             show_path_popup: false,
             show_pomodoro_popup: false,
             pomodoro_input: String::new(),
+            show_siren_popup: false,
             pomodoro_alert_expiry: None,
             config,
             path_list_state: ListState::default(),
             notification: None,
+            reminders: Vec::new(),
+            active_reminder: None,
+            snoozed_reminders: HashMap::new(),
+            daemon_tty: None,
+            current_focus: None,
+            todo_timer: None,
+            timers: Vec::new(),
+            show_timers_popup: false,
+            timers_state: ListState::default(),
+            timer_input: String::new(),
+            activity_started: Local::now(),
+            last_activity: Local::now(),
+            last_chime: Local::now(),
+            hyperfocus_nudged_at: None,
+        };
+        if show_mood_popup {
+            // 시작 체크인이 이미 지난 예약 시각의 체크인을 겸함
+            app.checkin_prompted_slot = app.latest_checkin_slot();
         }
+        if app.config.daemon.enabled
+            && let Some(tty) = daemon::current_tty()
+        {
+            // 앱을 닫은 뒤에는 데몬이 울리는 알림을 이 터미널에서 받음
+            app.daemon_request(daemon::Request::Attach {
+                tty: tty.clone(),
+                pid: std::process::id(),
+            });
+            app.daemon_tty = Some(tty);
+        }
+        app.rearm_reminders();
+        app.restore_focus();
+        app.restore_pomodoro();
+        app.check_goals();
+        app
     }
 
-    /// 현재 로그 파일(오늘)의 내용을 다시 읽어서 메모리 상의 로그 목록을 갱신합니다.
+    /// 현재 로그 파일(오늘, 또는 보고 있는 지난 날짜)의 내용을 다시 읽어서 메모리 상의 로그 목록을 갱신합니다.
     pub fn update_logs(&mut self) {
-        if let Ok(logs) = storage::read_today_entries(&self.config.data.log_path) {
+        let logs = match self.viewing_date {
+            Some(date) => storage::read_entries_on(&self.config.data.log_path, date),
+            None => storage::read_today_entries(&self.config.data.log_path),
+        };
+        if let Ok(logs) = logs {
             self.logs = logs;
             self.is_search_result = false;
             if !self.logs.is_empty() {
                 self.logs_state.select(Some(self.logs.len() - 1));
             }
+            self.clamp_task_selection();
+            // 알림/집중/목표는 오늘 로그 기준
+            if self.viewing_date.is_none() {
+                self.rearm_reminders();
+                self.validate_focus();
+                self.check_goals();
+            }
+        }
+    }
+
+    /// 지난 날짜의 로그를 메인 화면에 불러옵니다. 오늘(또는 이후)을 고르면 오늘로 돌아갑니다.
+    pub fn view_day(&mut self, date: NaiveDate) {
+        self.viewing_date = (date < Local::now().date_naive()).then_some(date);
+        self.focus = FocusPane::Logs;
+        self.update_logs();
+    }
+
+    /// 지난 날짜를 보고 있고 편집이 허용되지 않았는지 확인합니다.
+    pub fn is_read_only(&self) -> bool {
+        self.viewing_date.is_some() && !self.config.history.editable
+    }
+
+    /// 오늘의 목표, 할 일 모두 완료, 연속 기록 이정표를 확인하고 처음 달성한 것을 축하합니다.
+    /// 한 번 축하한 목표(하루 단위)와 이정표는 `state.toml`에 남겨 다시 축하하지 않습니다.
+    pub fn check_goals(&mut self) {
+        if self.is_search_result {
+            return;
+        }
+        let today = Local::now().date_naive();
+        let today_str = today.format("%Y-%m-%d").to_string();
+        if self.state.celebrated_date != today_str {
+            self.state.celebrated_date = today_str.clone();
+            self.state.celebrated.clear();
+        }
+
+        let entries = self
+            .logs
+            .iter()
+            .filter(|entry| {
+                parser::parse_checkin(&entry.content).is_none()
+                    && !storage::is_generated_entry(&entry.content)
+            })
+            .count();
+        let todos: Vec<bool> = self
+            .logs
+            .iter()
+            .filter_map(|entry| parser::extract_todo_content(&entry.content))
+            .map(|(checked, _)| checked)
+            .collect();
+        let done = todos.iter().filter(|checked| **checked).count();
+        let pomodoros = self.completed_work_sessions();
+
+        // 지난 기록 + 오늘 기록으로 연속 일수 계산
+        let streak = |active: fn(&DayActivity) -> bool, active_today: bool| {
+            let past = self
+                .activity_data
+                .iter()
+                .filter(|(date, day)| **date < today && active(day))
+                .map(|(date, _)| *date);
+            Streak::from_days(past.chain(active_today.then_some(today)), today)
+        };
+        self.log_streak = streak(|day| day.entries > 0, entries > 0);
+        self.todo_streak = streak(|day| day.todos_completed > 0, done > 0);
+
+        let goals = &self.config.goals;
+        let mut reached = Vec::new();
+        for (key, goal, value, what) in [
+            ("goal:todos", goals.todos_done, done, "todos done"),
+            ("goal:entries", goals.entries, entries, "entries logged"),
+            (
+                "goal:pomodoros",
+                goals.pomodoros,
+                pomodoros,
+                "focus sessions",
+            ),
+        ] {
+            if goal > 0 && value >= goal {
+                reached.push((key, format!("🎯 Daily goal reached: {} {}!", value, what)));
+            }
+        }
+        if !todos.is_empty() && todos.iter().all(|checked| *checked) {
+            reached.push((
+                "all_clear",
+                "✨ All of today's todos are cleared!".to_string(),
+            ));
+        }
+
+        let mut messages = Vec::new();
+        for (key, message) in reached {
+            if !self.state.celebrated.iter().any(|k| k == key) {
+                self.state.celebrated.push(key.to_string());
+                messages.push(message);
+            }
+        }
+        // 연속 기록 이정표는 새로 달성한 것 중 가장 큰 것만 축하
+        for (kind, current, label) in [
+            ("log", self.log_streak.current, "logging"),
+            ("todo", self.todo_streak.current, "todo"),
+        ] {
+            let mut newest = None;
+            for days in STREAK_MILESTONES.iter().filter(|days| **days <= current) {
+                let key = format!("streak:{}:{}", kind, days);
+                if let std::collections::btree_map::Entry::Vacant(entry) =
+                    self.state.milestones.entry(key)
+                {
+                    entry.insert(today_str.clone());
+                    newest = Some(days);
+                }
+            }
+            if let Some(days) = newest {
+                messages.push(format!("🔥 {}-day {} streak!", days, label));
+            }
+        }
+
+        if messages.is_empty() {
+            return;
+        }
+        let _ = storage::save_state(&self.config.data.log_path, &self.state);
+        match self.config.goals.celebration.as_str() {
+            "popup" => self.celebration = Some(messages.join("\n")),
+            "toast" => {
+                self.notification = Some((
+                    format!("🎉 {}", messages.join(" · ")),
+                    Local::now() + chrono::Duration::seconds(5),
+                ));
+            }
+            _ => {}
+        }
+    }
+
+    /// 뽀모도로 이벤트를 오늘 로그에 기록합니다.
+    fn log_pomodoro_event(&mut self, event: &PomodoroEvent) {
+        let _ = storage::append_entry(
+            &self.config.data.log_path,
+            &parser::format_pomodoro_event(event),
+        );
+        self.sync_daemon_pomodoro();
+        if !self.is_search_result {
+            self.update_logs();
+        }
+    }
+
+    /// 뽀모도로 타이머(집중 또는 휴식)를 시작하고 시작 기록을 남깁니다.
+    pub fn start_pomodoro(&mut self, phase: PomodoroPhase, minutes: i64, tags: Vec<String>) {
+        self.pomodoro_end = Some(Local::now() + chrono::Duration::minutes(minutes));
+        self.pomodoro_paused = None;
+        self.pomodoro_phase = phase;
+        self.pomodoro_next = None;
+        self.pomodoro_interruptions.clear();
+        if phase == PomodoroPhase::Work {
+            self.pomodoro_tags = tags.clone();
+        } else if !self.parking_lot.is_empty() {
+            // 휴식이 시작되면 집중 중에 미뤄둔 방해 목록을 검토
+            self.parking_lot_state.select(Some(0));
+            self.show_parking_lot_popup = true;
+        }
+        self.log_pomodoro_event(&PomodoroEvent::Started {
+            phase,
+            minutes,
+            tags,
+        });
+        self.update_pomodoro_session();
+    }
+
+    /// 현재 세션의 (내부, 외부) 방해 횟수를 반환합니다.
+    pub fn interruption_counts(&self) -> (usize, usize) {
+        let external = self
+            .pomodoro_interruptions
+            .iter()
+            .filter(|i| i.external)
+            .count();
+        (self.pomodoro_interruptions.len() - external, external)
+    }
+
+    /// 방해 입력 창을 엽니다. 집중 단계가 진행 중일 때만 열립니다.
+    pub fn open_interruption_popup(&mut self) {
+        if self.pomodoro_active() && self.pomodoro_phase == PomodoroPhase::Work {
+            self.interruption_input.clear();
+            self.interruption_external = false;
+            self.show_interruption_popup = true;
+        } else {
+            self.notification = Some((
+                "No focus session running".to_string(),
+                Local::now() + chrono::Duration::seconds(2),
+            ));
+        }
+    }
+
+    /// 입력한 방해를 로그에 남기고 휴식 때 검토할 목록에 넣습니다.
+    pub fn record_interruption(&mut self) {
+        self.show_interruption_popup = false;
+        let text = self.interruption_input.trim().to_string();
+        if text.is_empty() {
+            return;
+        }
+        let interruption = Interruption {
+            external: self.interruption_external,
+            text,
+        };
+        let _ = storage::append_entry(
+            &self.config.data.log_path,
+            &parser::format_interruption(&interruption),
+        );
+        if !self.is_search_result {
+            self.update_logs();
+        }
+        self.pomodoro_interruptions.push(interruption.clone());
+        self.parking_lot.push(interruption);
+    }
+
+    /// 검토 목록에서 선택한 방해를 오늘의 할 일로 옮깁니다.
+    pub fn parking_lot_to_todo(&mut self) {
+        let Some(i) = self.parking_lot_state.selected() else {
+            return;
+        };
+        if i >= self.parking_lot.len() {
+            return;
+        }
+        let interruption = self.parking_lot.remove(i);
+        let _ = storage::append_entry(
+            &self.config.data.log_path,
+            &parser::format_todo(&interruption.text, false),
+        );
+        if !self.is_search_result {
+            self.update_logs();
+        }
+        self.after_parking_lot_change(i);
+    }
+
+    /// 검토 목록에서 선택한 방해를 할 일로 만들지 않고 버립니다.
+    pub fn discard_parking_lot_item(&mut self) {
+        if let Some(i) = self.parking_lot_state.selected()
+            && i < self.parking_lot.len()
+        {
+            self.parking_lot.remove(i);
+            self.after_parking_lot_change(i);
+        }
+    }
+
+    /// 검토를 마치고 목록을 비웁니다. (원래 기록은 로그에 남아 있음)
+    pub fn close_parking_lot(&mut self) {
+        self.parking_lot.clear();
+        self.show_parking_lot_popup = false;
+    }
+
+    fn after_parking_lot_change(&mut self, removed: usize) {
+        if self.parking_lot.is_empty() {
+            self.close_parking_lot();
+        } else {
+            self.parking_lot_state
+                .select(Some(removed.min(self.parking_lot.len() - 1)));
+        }
+    }
+
+    /// 종료 알림이 떠 있고 아직 최소 차단 시간이 지나지 않았는지 확인합니다.
+    pub fn siren_input_blocked(&self) -> bool {
+        self.show_siren_popup
+            && self
+                .pomodoro_alert_expiry
+                .is_some_and(|expiry| Local::now() < expiry)
+    }
+
+    /// 진행 중(일시정지 포함)인 뽀모도로가 있는지 확인합니다.
+    pub fn pomodoro_active(&self) -> bool {
+        self.pomodoro_end.is_some() || self.pomodoro_paused.is_some()
+    }
+
+    /// 진행 중인 뽀모도로를 중단하고 중단 기록을 남깁니다. 사이클도 함께 끝납니다.
+    pub fn stop_pomodoro(&mut self) {
+        self.pomodoro_next = None;
+        let was_active = self.pomodoro_active();
+        self.pomodoro_end = None;
+        self.pomodoro_paused = None;
+        if was_active {
+            self.log_pomodoro_event(&PomodoroEvent::Stopped);
+        }
+    }
+
+    /// 뽀모도로를 일시정지하거나, 일시정지 상태라면 남은 시간 그대로 다시 시작합니다.
+    pub fn toggle_pomodoro_pause(&mut self) {
+        if let Some(remaining) = self.pomodoro_paused.take() {
+            self.pomodoro_end = Some(Local::now() + remaining);
+            self.log_pomodoro_event(&PomodoroEvent::Resumed);
+        } else if let Some(end) = self.pomodoro_end.take() {
+            self.pomodoro_paused = Some((end - Local::now()).max(chrono::Duration::zero()));
+            self.log_pomodoro_event(&PomodoroEvent::Paused);
+        }
+    }
+
+    /// 진행 중(일시정지 포함)인 뽀모도로의 남은 시간을 설정된 만큼 늘립니다.
+    pub fn extend_pomodoro(&mut self) {
+        let minutes = self.config.pomodoro.extend_minutes;
+        let extra = chrono::Duration::minutes(minutes);
+        if let Some(end) = &mut self.pomodoro_end {
+            *end += extra;
+        } else if let Some(remaining) = &mut self.pomodoro_paused {
+            *remaining += extra;
+        } else {
+            return;
+        }
+        self.log_pomodoro_event(&PomodoroEvent::Extended { minutes });
+    }
+
+    /// 남은 시간을 반환합니다. 일시정지 중이면 멈춰 있는 남은 시간입니다.
+    pub fn pomodoro_remaining(&self) -> Option<chrono::Duration> {
+        match (self.pomodoro_end, self.pomodoro_paused) {
+            (_, Some(remaining)) => Some(remaining),
+            (Some(end), None) => Some((end - Local::now()).max(chrono::Duration::zero())),
+            (None, None) => None,
+        }
+    }
+
+    /// 뽀모도로 타이머가 끝났을 때 완료 기록을 남기고 다음 단계로 넘어갑니다.
+    /// `auto_advance` 설정이 꺼져 있으면 다음 단계는 확인 후 시작합니다.
+    pub fn complete_pomodoro(&mut self) {
+        self.finish_pomodoro(self.config.pomodoro.auto_advance);
+    }
+
+    /// 완료를 기록하고, `auto_advance`가 아니면 다음 단계를 시작할지 묻습니다.
+    fn finish_pomodoro(&mut self, auto_advance: bool) {
+        if self.pomodoro_end.take().is_none() {
+            return;
+        }
+        if self.pomodoro_phase == PomodoroPhase::Work {
+            // 목표 확인(로그 기록 직후)이 이번 세션까지 세도록 먼저 올림
+            let today = Local::now().date_naive();
+            let completed = self.completed_work_sessions();
+            self.pomodoro_completed = (today, completed + 1);
+        }
+        let (internal, external) = self.interruption_counts();
+        self.log_pomodoro_event(&PomodoroEvent::Completed { internal, external });
+
+        let next = self.next_pomodoro_phase();
+        if auto_advance {
+            self.start_next_pomodoro_phase(next);
+        } else {
+            self.pomodoro_next = Some(next);
+        }
+    }
+
+    /// 방금 끝난 단계 다음에 올 단계를 계산합니다.
+    /// 집중이 끝나면 휴식(`long_break_every`번째마다 긴 휴식), 휴식이 끝나면 다시 집중입니다.
+    fn next_pomodoro_phase(&self) -> PomodoroPhase {
+        match self.pomodoro_phase {
+            PomodoroPhase::Work => {
+                let every = self.config.pomodoro.long_break_every.max(1);
+                if self.completed_work_sessions().is_multiple_of(every) {
+                    PomodoroPhase::LongBreak
+                } else {
+                    PomodoroPhase::ShortBreak
+                }
+            }
+            PomodoroPhase::ShortBreak | PomodoroPhase::LongBreak => PomodoroPhase::Work,
+        }
+    }
+
+    /// 설정된 시간으로 다음 단계를 시작합니다. 집중 단계는 이전 집중의 태그를 이어받습니다.
+    pub fn start_next_pomodoro_phase(&mut self, phase: PomodoroPhase) {
+        let pomodoro = &self.config.pomodoro;
+        let minutes = match phase {
+            PomodoroPhase::Work => pomodoro.work_minutes,
+            PomodoroPhase::ShortBreak => pomodoro.short_break_minutes,
+            PomodoroPhase::LongBreak => pomodoro.long_break_minutes,
+        };
+        let tags = match phase {
+            PomodoroPhase::Work => self.pomodoro_tags.clone(),
+            _ => Vec::new(),
+        };
+        self.start_pomodoro(phase, minutes, tags);
+    }
+
+    /// 오늘 끝까지 마친 집중 세션의 수입니다. 날짜가 바뀌었으면 0부터 다시 셉니다.
+    fn completed_work_sessions(&self) -> usize {
+        let (date, count) = self.pomodoro_completed;
+        if date == Local::now().date_naive() {
+            count
+        } else {
+            0
+        }
+    }
+
+    /// 현재 단계 기준으로 집중 세션 순번을 다시 계산합니다.
+    /// 집중 중이면 이번 세션의 순번, 휴식 중이면 직전 집중 세션의 순번입니다.
+    fn update_pomodoro_session(&mut self) {
+        let completed = self.completed_work_sessions();
+        self.pomodoro_session = match self.pomodoro_phase {
+            PomodoroPhase::Work => completed + 1,
+            _ => completed.max(1),
+        };
+    }
+
+    /// 오늘 로그의 뽀모도로 기록을 재생하여, 앱이 꺼지기 전에 진행 중이던 타이머를 복원합니다.
+    /// 일시정지/연장 기록도 반영하며, 꺼져 있는 동안 이미 끝난 타이머는 완료로 기록합니다.
+    fn restore_pomodoro(&mut self) {
+        let Some(replay) = replay_pomodoro(&self.logs, Local::now().date_naive()) else {
+            return;
+        };
+        self.pomodoro_phase = replay.phase;
+        self.pomodoro_tags = replay.tags;
+        if replay.phase == PomodoroPhase::Work {
+            self.parking_lot = replay.interruptions.clone();
+            self.pomodoro_interruptions = replay.interruptions;
+        }
+        self.update_pomodoro_session();
+
+        if let Some(paused_at) = replay.paused_at {
+            self.pomodoro_paused = Some(replay.end - paused_at);
+            return;
+        }
+        let Some(end) = Local.from_local_datetime(&replay.end).earliest() else {
+            return;
+        };
+        self.pomodoro_end = Some(end);
+        if end <= Local::now() {
+            // 앱이 꺼져 있는 동안 끝난 단계: 알림만 띄우고 다음 단계는 자동으로 넘기지 않음
+            self.finish_pomodoro(false);
+            self.show_siren_popup = true;
+        } else {
+            self.sync_daemon_pomodoro();
+        }
+    }
+
+    /// 데몬을 쓰도록 설정했다면 요청을 보냅니다. 데몬이 떠 있지 않으면 조용히 무시합니다.
+    /// 앱이 떠 있는 동안 이 터미널의 알림은 앱이 직접 울리고, 데몬은 다른 터미널과 명령만 맡습니다.
+    fn daemon_request(&self, request: daemon::Request) {
+        if self.config.daemon.enabled {
+            let _ = daemon::request(&self.config.daemon.socket_path, &request);
+        }
+    }
+
+    /// 앱을 닫을 때 데몬에 알려, 이후로는 이 터미널에도 데몬이 알림을 울리게 합니다.
+    pub fn detach_daemon(&mut self) {
+        if let Some(tty) = self.daemon_tty.take() {
+            self.daemon_request(daemon::Request::Detach(tty));
+        }
+    }
+
+    /// 진행 중인 뽀모도로의 종료 시각을 데몬에 맞춰둡니다. (멈췄거나 끝났으면 취소)
+    /// 앱을 닫아도 데몬이 대신 알림을 울려줍니다.
+    fn sync_daemon_pomodoro(&mut self) {
+        let key = "pomodoro".to_string();
+        self.daemon_request(match self.pomodoro_end {
+            Some(end) => daemon::Request::Add {
+                key,
+                due: end.timestamp(),
+                message: format!(
+                    "{} {} finished",
+                    self.pomodoro_phase.icon(),
+                    self.pomodoro_phase.label()
+                ),
+            },
+            None => daemon::Request::Cancel(key),
+        });
+    }
+
+    /// 오늘 로그의 마지막 `Focus:` 기록에서 집중 중인 할 일을 복원합니다.
+    fn restore_focus(&mut self) {
+        self.current_focus = match self
+            .logs
+            .iter()
+            .rev()
+            .find_map(|entry| parser::parse_focus_entry(&entry.content))
+        {
+            Some(parser::FocusEntry::Set(todo)) => Some(todo.to_string()),
+            Some(parser::FocusEntry::Cleared) | None => None,
+        };
+        self.validate_focus();
+    }
+
+    /// 집중 중인 할 일이 더 이상 미완료 상태가 아니면(완료 처리 등) 집중 표시를 해제합니다.
+    fn validate_focus(&mut self) {
+        if let Some(focus) = &self.current_focus {
+            let still_pending = self.logs.iter().any(|entry| {
+                parser::extract_pending_content(&entry.content).as_deref() == Some(focus)
+            });
+            if !still_pending {
+                self.current_focus = None;
+            }
+        }
+    }
+
+    /// 현재 패널에서 선택된 할 일을 집중 대상으로 지정하고 `Focus:` 로그를 남깁니다.
+    /// 이미 집중 중인 할 일을 다시 선택하면 집중 표시를 해제합니다.
+    /// 집중은 오늘의 상태라서 기록은 항상 오늘 로그에 남고, 지난 날짜를 보는 중에는 지정할 수 없습니다.
+    pub fn focus_selected_todo(&mut self) {
+        if self.viewing_date.is_some() {
+            self.notification = Some((
+                "🎯 Focus works on today's tasks (Esc: back to today)".to_string(),
+                Local::now() + chrono::Duration::seconds(2),
+            ));
+            return;
+        }
+        let index = match self.focus {
+            FocusPane::Tasks => self.selected_task_index(),
+            FocusPane::Logs => self.logs_state.selected(),
+        };
+        let Some(todo) = index
+            .and_then(|i| self.logs.get(i))
+            .and_then(|entry| parser::extract_pending_content(&entry.content))
+        else {
+            return;
+        };
+
+        let cleared = self.current_focus.as_deref() == Some(todo.as_str());
+        let log = parser::format_focus_entry((!cleared).then_some(todo.as_str()));
+        let _ =
+            storage::append_entry_on(&self.config.data.log_path, Local::now().date_naive(), &log);
+        self.current_focus = if cleared { None } else { Some(todo) };
+
+        // 검색 결과 화면이 아니면 방금 남긴 Focus 로그를 반영 (커서 위치는 유지)
+        if !self.is_search_result {
+            let (logs_sel, tasks_sel) = (self.logs_state.selected(), self.tasks_state.selected());
+            self.update_logs();
+            self.logs_state.select(logs_sel);
+            self.tasks_state.select(tasks_sel);
+        }
+    }
+
+    /// 오늘 로그에서 시각 알림을 다시 수집합니다. 미뤄둔(snooze) 알림은 미룬 시각을 사용합니다.
+    pub fn rearm_reminders(&mut self) {
+        let mut reminders = storage::collect_reminders(&self.logs);
+        for reminder in &mut reminders {
+            let key = (reminder.entry.file_path.clone(), reminder.entry.line_number);
+            if let Some(snoozed) = self.snoozed_reminders.get(&key) {
+                reminder.due = *snoozed;
+            }
+        }
+        self.reminders = reminders;
+    }
+
+    /// 울릴 시각이 된 알림이 있으면 화면에 띄웁니다. 새 알림이 떴다면 `true`를 반환합니다.
+    pub fn fire_due_reminder(&mut self) -> bool {
+        if self.active_reminder.is_some() {
+            return false;
+        }
+        let now = Local::now().naive_local();
+        if let Some(pos) = self.reminders.iter().position(|r| r.due <= now) {
+            self.active_reminder = Some(self.reminders.remove(pos));
+            return true;
+        }
+        false
+    }
+
+    /// 떠 있는 알림을 확인 처리합니다. 파일에 기록되어 다음 실행 때도 다시 울리지 않습니다.
+    pub fn dismiss_reminder(&mut self) {
+        if let Some(reminder) = self.active_reminder.take() {
+            let key = (reminder.entry.file_path.clone(), reminder.entry.line_number);
+            self.snoozed_reminders.remove(&key);
+            let _ = storage::dismiss_reminder(&reminder.entry);
+            if !self.is_search_result {
+                self.update_logs();
+            }
+        }
+    }
+
+    /// 떠 있는 알림을 설정된 시간만큼 미룹니다.
+    pub fn snooze_reminder(&mut self) {
+        if let Some(mut reminder) = self.active_reminder.take() {
+            let key = (reminder.entry.file_path.clone(), reminder.entry.line_number);
+            reminder.due = Local::now().naive_local()
+                + chrono::Duration::minutes(self.config.reminder.snooze_minutes);
+            self.snoozed_reminders.insert(key, reminder.due);
+            // 앱을 닫아도 미룬 시각에 울리도록 데몬에도 등록
+            if let Some(due) = Local.from_local_datetime(&reminder.due).earliest() {
+                self.daemon_request(daemon::Request::Add {
+                    key: reminder.daemon_key(),
+                    due: due.timestamp(),
+                    message: format!("🔔 {}", reminder.text),
+                });
+            }
+            self.reminders.push(reminder);
+        }
+    }
+
+    /// 입력한 내용(`빨래 40m`, `통화`)으로 카운트다운 또는 스톱워치를 추가합니다.
+    pub fn add_timer_from_input(&mut self) {
+        let Some((name, minutes)) = parser::parse_timer_spec(&self.timer_input) else {
+            return;
+        };
+        self.timer_input.clear();
+        let now = Local::now();
+        let timer = NamedTimer {
+            name,
+            started: now,
+            end: minutes.map(|m| now + chrono::Duration::minutes(m)),
+        };
+        if let Some(end) = timer.end {
+            self.daemon_request(daemon::Request::Add {
+                key: timer.daemon_key(),
+                due: end.timestamp(),
+                message: format!("⏲ {} done!", timer.name),
+            });
+        }
+        self.log_timer(&match minutes {
+            Some(m) => format!("{} {} started", timer.name, parser::format_duration(m)),
+            None => format!("{} stopwatch started", timer.name),
+        });
+        self.timers.push(timer);
+        self.timers_state.select(Some(self.timers.len() - 1));
+    }
+
+    /// 선택한 타이머를 멈추고 목록에서 지웁니다. 스톱워치는 잰 시간을 기록합니다.
+    pub fn remove_selected_timer(&mut self) {
+        let Some(i) = self.timers_state.selected() else {
+            return;
+        };
+        if i >= self.timers.len() {
+            return;
+        }
+        let timer = self.timers.remove(i);
+        if timer.end.is_some() {
+            self.daemon_request(daemon::Request::Cancel(timer.daemon_key()));
+        }
+        let elapsed = (Local::now() - timer.started).num_minutes();
+        self.log_timer(&format!(
+            "{} stopped after {}",
+            timer.name,
+            parser::format_duration(elapsed)
+        ));
+        self.timers_state.select(if self.timers.is_empty() {
+            None
+        } else {
+            Some(i.min(self.timers.len() - 1))
+        });
+    }
+
+    /// 시간이 다 된 카운트다운을 목록에서 꺼내 알림을 띄우고 반환합니다.
+    pub fn take_finished_timers(&mut self) -> Vec<NamedTimer> {
+        let now = Local::now();
+        let (finished, running): (Vec<NamedTimer>, Vec<NamedTimer>) =
+            std::mem::take(&mut self.timers)
+                .into_iter()
+                .partition(|timer| timer.end.is_some_and(|end| now >= end));
+        self.timers = running;
+        if finished.is_empty() {
+            return finished;
+        }
+
+        if let Some(selected) = self.timers_state.selected() {
+            self.timers_state.select(if self.timers.is_empty() {
+                None
+            } else {
+                Some(selected.min(self.timers.len() - 1))
+            });
+        }
+        for timer in &finished {
+            self.log_timer(&format!("{} finished", timer.name));
+        }
+        let names: Vec<&str> = finished.iter().map(|timer| timer.name.as_str()).collect();
+        self.notification = Some((
+            format!("⏲ {} done!", names.join(", ")),
+            now + chrono::Duration::seconds(10),
+        ));
+        finished
+    }
+
+    /// 설정에서 켠 경우에만 타이머 기록을 로그에 남깁니다.
+    fn log_timer(&mut self, body: &str) {
+        if !self.config.timers.log {
+            return;
+        }
+        let _ = storage::append_entry(
+            &self.config.data.log_path,
+            &format!("{}{}", parser::TIMER_PREFIX, body),
+        );
+        if !self.is_search_result {
+            self.update_logs();
+        }
+    }
+
+    /// 선택한 할 일의 작업 타이머를 시작합니다. 이미 돌고 있다면 멈추고 사용 시간을 기록하며,
+    /// 다른 할 일을 선택한 상태였다면 그 할 일로 타이머를 옮겨 시작합니다.
+    pub fn toggle_todo_timer(&mut self) {
+        let selected = match self.focus {
+            FocusPane::Tasks => self.selected_task_index(),
+            FocusPane::Logs => self.logs_state.selected(),
+        };
+        let target = selected
+            .and_then(|i| self.logs.get(i))
+            .and_then(|entry| parser::extract_pending_content(&entry.content));
+
+        let running = self.todo_timer.as_ref().map(|(todo, _)| todo.clone());
+        self.stop_todo_timer();
+
+        match target {
+            Some(todo) if running.as_ref() != Some(&todo) => {
+                self.todo_timer = Some((todo, Local::now()));
+            }
+            None if running.is_none() => {
+                self.notification = Some((
+                    "Select a todo first".to_string(),
+                    Local::now() + chrono::Duration::seconds(2),
+                ));
+            }
+            _ => {}
+        }
+    }
+
+    /// 작업 타이머를 멈추고, 흐른 시간을 해당 할 일의 `spent:` 주석에 더합니다.
+    pub fn stop_todo_timer(&mut self) {
+        let Some((todo, started)) = self.todo_timer.take() else {
+            return;
+        };
+        let minutes = ((Local::now() - started).num_seconds() + 30) / 60;
+        if minutes <= 0 {
+            return;
+        }
+        let entry = self
+            .logs
+            .iter()
+            .find(|entry| parser::extract_pending_content(&entry.content).as_ref() == Some(&todo))
+            .cloned()
+            .or_else(|| {
+                storage::read_today_entries(&self.config.data.log_path)
+                    .unwrap_or_default()
+                    .into_iter()
+                    .find(|entry| {
+                        parser::extract_pending_content(&entry.content).as_ref() == Some(&todo)
+                    })
+            });
+        if let Some(entry) = entry {
+            let _ = storage::add_spent_minutes(&entry, minutes);
+            if !self.is_search_result {
+                self.update_logs();
+            }
+        }
+    }
+
+    /// 해당 할 일에 지금까지 쓴 시간(분)을 반환합니다. 타이머가 돌고 있다면 진행 중인 시간도 포함합니다.
+    pub fn todo_spent_minutes(&self, line: &str, content: &str) -> Option<i64> {
+        let running = self
+            .todo_timer
+            .as_ref()
+            .filter(|(todo, _)| todo == content)
+            .map(|(_, started)| (Local::now() - *started).num_minutes());
+        match (parser::parse_spent(line), running) {
+            (None, None) => None,
+            (spent, running) => Some(spent.unwrap_or(0) + running.unwrap_or(0)),
+        }
+    }
+
+    /// 커서 앞에서 입력 중인 태그(`#...`)가 있으면 자동완성 후보를 다시 고릅니다.
+    pub fn update_tag_suggestions(&mut self) {
+        let (row, col) = self.textarea.cursor();
+        let prefix = self
+            .textarea
+            .lines()
+            .get(row)
+            .and_then(|line| parser::tag_prefix_at(line, col));
+        self.tag_suggestions = match prefix {
+            Some(prefix) => parser::complete_tag(&self.known_tags, prefix, 6),
+            None => Vec::new(),
+        };
+        self.tag_suggestion_index = 0;
+    }
+
+    /// 방금 저장한 글의 태그를 자동완성 후보에 반영합니다. 방금 쓴 태그가 맨 앞에 옵니다.
+    pub fn remember_tags(&mut self, text: &str) {
+        for line in text.lines() {
+            for token in parser::tokenize(line) {
+                let parser::LogToken::Tag(word) = token else {
+                    continue;
+                };
+                let Some(tag) = parser::normalize_tag(word) else {
+                    continue;
+                };
+                let count = match self.known_tags.iter().position(|(t, _)| *t == tag) {
+                    Some(i) => self.known_tags.remove(i).1 + 1,
+                    None => 1,
+                };
+                self.known_tags.insert(0, (tag, count));
+            }
+        }
+    }
+
+    /// 선택한 후보로 입력 중인 태그를 바꿉니다. 커서 뒤에 이어지는 태그의 나머지도 바꿉니다.
+    pub fn accept_tag_suggestion(&mut self) {
+        let Some(tag) = self.tag_suggestions.get(self.tag_suggestion_index).cloned() else {
+            return;
+        };
+        let (row, col) = self.textarea.cursor();
+        let line = self.textarea.lines().get(row).cloned().unwrap_or_default();
+        let Some(prefix) = parser::tag_prefix_at(&line, col) else {
+            return;
+        };
+        let suffix = parser::word_suffix_at(&line, col);
+        for _ in 0..prefix.chars().count() {
+            self.textarea.delete_char();
+        }
+        for _ in 0..suffix.chars().count() {
+            self.textarea.delete_next_char();
+        }
+        self.textarea.insert_str(&tag);
+        // 뒤에 이미 공백이 있으면 공백을 더 넣지 않고 그 뒤로 넘어감
+        let (row, col) = self.textarea.cursor();
+        let next = self.textarea.lines()[row].chars().nth(col);
+        if next.is_some_and(char::is_whitespace) {
+            self.textarea.move_cursor(CursorMove::Forward);
+        } else {
+            self.textarea.insert_char(' ');
+        }
+        self.tag_suggestions.clear();
+    }
+
+    /// 태그 트리를 새로 읽어 태그 팝업을 엽니다. 태그가 하나도 없으면 열지 않습니다.
+    pub fn open_tag_popup(&mut self) {
+        let Ok(tags) = storage::get_all_tags(&self.config.data.log_path) else {
+            return;
+        };
+        self.tag_tree = build_tag_tree(&tags);
+        if !self.tag_tree.is_empty() {
+            self.tag_list_state.select(Some(0));
+            self.show_tag_popup = true;
+        }
+    }
+
+    /// 접힌 상위 태그 아래를 빼고, 지금 보이는 태그들입니다.
+    pub fn visible_tags(&self) -> Vec<&TagNode> {
+        let mut visible = Vec::new();
+        let mut collapsed_depth: Option<usize> = None;
+        for node in &self.tag_tree {
+            if let Some(depth) = collapsed_depth {
+                if node.depth > depth {
+                    continue;
+                }
+                collapsed_depth = None;
+            }
+            if node.has_children && self.collapsed_tags.contains(&node.path) {
+                collapsed_depth = Some(node.depth);
+            }
+            visible.push(node);
+        }
+        visible
+    }
+
+    pub fn selected_tag(&self) -> Option<&TagNode> {
+        self.tag_list_state
+            .selected()
+            .and_then(|i| self.visible_tags().get(i).copied())
+    }
+
+    pub fn move_tag_selection(&mut self, delta: isize) {
+        let len = self.visible_tags().len();
+        if len == 0 {
+            return;
+        }
+        let current = self.tag_list_state.selected().unwrap_or(0);
+        let next = current.saturating_add_signed(delta).min(len - 1);
+        self.tag_list_state.select(Some(next));
+    }
+
+    /// 선택한 태그를 접습니다. 이미 접혀 있거나 하위가 없으면 상위 태그로 이동합니다.
+    pub fn collapse_selected_tag(&mut self) {
+        let Some(node) = self.selected_tag() else {
+            return;
+        };
+        let path = node.path.clone();
+        if node.has_children && !self.collapsed_tags.contains(&path) {
+            self.collapsed_tags.insert(path);
+        } else if let Some(parent) = node.parent().map(str::to_string)
+            && let Some(index) = self.visible_tags().iter().position(|n| n.path == parent)
+        {
+            self.tag_list_state.select(Some(index));
+        }
+    }
+
+    /// 선택한 태그를 펼칩니다. 이미 펼쳐져 있으면 첫 하위 태그로 이동합니다.
+    pub fn expand_selected_tag(&mut self) {
+        let Some(node) = self.selected_tag() else {
+            return;
+        };
+        if !node.has_children {
+            return;
+        }
+        let path = node.path.clone();
+        if !self.collapsed_tags.remove(&path) {
+            self.move_tag_selection(1);
+        }
+    }
+
+    /// 시간대 분포를 현재 태그 필터로 다시 집계합니다.
+    pub fn refresh_hour_stats(&mut self) {
+        if let Ok(stats) =
+            storage::get_hour_stats(&self.config.data.log_path, &self.hour_tag_filter)
+        {
+            self.hour_stats = stats;
+        }
+    }
+
+    /// 체크인 팝업을 엽니다. (기분 → 에너지 → 집중도 순서)
+    pub fn open_checkin(&mut self) {
+        if self.config.moods.is_empty() {
+            return;
+        }
+        self.checkin_step = CheckinStep::Mood;
+        self.checkin_energy = 3;
+        self.checkin_focus = 3;
+        self.mood_list_state.select(Some(0));
+        self.show_mood_popup = true;
+    }
+
+    /// 체크인을 로그에 기록하고 팝업을 닫습니다. 건너뛴 점수는 기록하지 않습니다.
+    pub fn finish_checkin(&mut self, energy: Option<u8>, focus: Option<u8>) {
+        self.show_mood_popup = false;
+        let Some(mood) = self
+            .mood_list_state
+            .selected()
+            .and_then(|i| self.config.moods.get(i))
+        else {
+            return;
+        };
+        let _ = storage::append_entry(
+            &self.config.data.log_path,
+            &parser::format_mood_entry(mood, energy, focus),
+        );
+        self.update_logs();
+    }
+
+    /// 설정된 체크인 시각 중 지금 이전의 가장 늦은 시각을 반환합니다.
+    fn latest_checkin_slot(&self) -> Option<NaiveTime> {
+        checkin_slot_before(&self.config.checkin.times, Local::now().time())
+    }
+
+    /// 예약된 체크인 시각이 지났는데 그 뒤로 체크인을 하지 않았다면 체크인 팝업을 띄웁니다.
+    /// 한 시각에 한 번만 묻습니다(취소해도 다시 묻지 않음).
+    pub fn check_scheduled_checkin(&mut self) -> bool {
+        let Some(slot) = self.latest_checkin_slot() else {
+            return false;
+        };
+        if self.checkin_prompted_slot == Some(slot) || self.show_mood_popup {
+            return false;
+        }
+        self.checkin_prompted_slot = Some(slot);
+
+        let today = Local::now().date_naive();
+        let slot_time = today.and_time(slot);
+        let entries = storage::read_today_entries(&self.config.data.log_path).unwrap_or_default();
+        let checked_in = entries.iter().any(|entry| {
+            parser::parse_checkin(&entry.content).is_some()
+                && parser::parse_entry_time(&entry.content, today).is_some_and(|t| t >= slot_time)
+        });
+        if checked_in {
+            return false;
+        }
+        self.open_checkin();
+        true
+    }
+
+    /// 오늘 마지막 체크인 기록을 반환합니다.
+    pub fn latest_checkin(&self) -> Option<Checkin> {
+        if self.is_search_result || self.viewing_date.is_some() {
+            return None;
+        }
+        self.logs
+            .iter()
+            .rev()
+            .find_map(|entry| parser::parse_checkin(&entry.content))
+    }
+
+    /// 활동 히트맵에 보여줄 기간(시작 주의 월요일, 마지막 날)입니다.
+    /// 선택한 날짜가 속한 1년(오늘 기준으로 1년 단위로 끊음)을 53주로 보여줍니다.
+    pub fn activity_range(&self) -> (NaiveDate, NaiveDate) {
+        let today = Local::now().date_naive();
+        let mut end = today;
+        while self.activity_cursor <= end - chrono::Duration::weeks(52) {
+            end = end - Months::new(12);
+        }
+        let start = end - chrono::Duration::weeks(52);
+        let start = start - chrono::Duration::days(start.weekday().num_days_from_monday() as i64);
+        (start, end)
+    }
+
+    /// 히트맵 커서를 `days`일 옮깁니다. 오늘 이후로는 가지 않습니다.
+    pub fn move_activity_cursor(&mut self, days: i64) {
+        let today = Local::now().date_naive();
+        self.activity_cursor = (self.activity_cursor + chrono::Duration::days(days)).min(today);
+    }
+
+    /// 히트맵을 1년 단위로 넘깁니다. (`years`가 음수면 과거로)
+    pub fn page_activity_year(&mut self, years: i32) {
+        let months = Months::new(12 * years.unsigned_abs());
+        let moved = if years < 0 {
+            self.activity_cursor.checked_sub_months(months)
+        } else {
+            self.activity_cursor.checked_add_months(months)
+        };
+        if let Some(date) = moved {
+            self.activity_cursor = date.min(Local::now().date_naive());
+        }
+    }
+
+    /// 키 입력이 있었음을 기록합니다. 설정된 시간 이상 쉬었다면 활동을 새로 시작한 것으로 봅니다.
+    pub fn record_activity(&mut self) {
+        let now = Local::now();
+        if now - self.last_activity > chrono::Duration::minutes(self.config.nudge.break_minutes) {
+            self.activity_started = now;
+            self.hyperfocus_nudged_at = None;
+        }
+        self.last_activity = now;
+    }
+
+    /// 주기 알림과 과몰입 경고를 확인하고, 띄울 알림이 있으면 화면에 표시한 뒤 문구를 반환합니다.
+    /// 조용한 시간대에는 상태만 갱신하고 알리지 않습니다.
+    pub fn check_nudges(&mut self) -> Option<String> {
+        let now = Local::now();
+        let nudge = &self.config.nudge;
+        let break_gap = chrono::Duration::minutes(nudge.break_minutes);
+
+        // 뽀모도로 휴식 중이거나 한동안 입력이 없었다면 쉰 것으로 봄
+        let on_break = self.pomodoro_active() && self.pomodoro_phase != PomodoroPhase::Work;
+        if on_break || now - self.last_activity > break_gap {
+            self.activity_started = now;
+            self.hyperfocus_nudged_at = None;
+        }
+        let streak = now - self.activity_started;
+
+        let mut message = None;
+
+        if nudge.chime_minutes > 0 {
+            let minutes_of_day = i64::from(now.hour() * 60 + now.minute());
+            let slot = minutes_of_day - minutes_of_day % nudge.chime_minutes;
+            let slot_start =
+                now.date_naive().and_time(NaiveTime::MIN) + chrono::Duration::minutes(slot);
+            if self.last_chime.naive_local() < slot_start {
+                self.last_chime = now;
+                message = Some(if streak.num_minutes() > 0 {
+                    format!(
+                        "⏰ It's {}, you've been in this for {}",
+                        now.format("%H:%M"),
+                        parser::format_duration(streak.num_minutes())
+                    )
+                } else {
+                    format!("⏰ It's {}", now.format("%H:%M"))
+                });
+            }
+        }
+
+        if nudge.hyperfocus_minutes > 0 {
+            let limit = chrono::Duration::minutes(nudge.hyperfocus_minutes);
+            let due = self.hyperfocus_nudged_at.is_none_or(|at| now - at >= limit);
+            if streak >= limit && due {
+                self.hyperfocus_nudged_at = Some(now);
+                message = Some(format!(
+                    "🧘 {} without a break. Stand up, stretch, drink water?",
+                    parser::format_duration(streak.num_minutes())
+                ));
+            }
+        }
+
+        if parser::in_time_ranges(&nudge.quiet_hours, now.time()) {
+            return None;
+        }
+        let message = message?;
+        self.notification = Some((message.clone(), now + chrono::Duration::seconds(10)));
+        Some(message)
+    }
+
+    /// 오늘의 할 일 패널에 표시되는(미완료) 할 일들의 `logs` 인덱스 목록을 반환합니다.
+    pub fn pending_task_indices(&self) -> Vec<usize> {
+        self.logs
+            .iter()
+            .enumerate()
+            .filter(|(_, entry)| parser::extract_pending_content(&entry.content).is_some())
+            .map(|(i, _)| i)
+            .collect()
+    }
+
+    /// 할 일 패널 커서가 가리키는 할 일의 `logs` 인덱스를 반환합니다.
+    pub fn selected_task_index(&self) -> Option<usize> {
+        let selected = self.tasks_state.selected()?;
+        self.pending_task_indices().get(selected).copied()
+    }
+
+    /// 할 일 목록이 바뀐 뒤 패널 커서가 범위를 벗어나지 않도록 보정합니다.
+    fn clamp_task_selection(&mut self) {
+        let count = self.pending_task_indices().len();
+        if count == 0 {
+            self.tasks_state.select(None);
+        } else {
+            let i = self.tasks_state.selected().unwrap_or(0).min(count - 1);
+            self.tasks_state.select(Some(i));
+        }
+    }
+
+    /// 로그 패널과 할 일 패널 사이에서 포커스를 전환합니다.
+    pub fn toggle_focus(&mut self) {
+        self.focus = match self.focus {
+            FocusPane::Logs => FocusPane::Tasks,
+            FocusPane::Tasks => FocusPane::Logs,
+        };
+        if self.focus == FocusPane::Tasks {
+            self.clamp_task_selection();
+        }
+    }
+
+    /// 할 일 패널 커서를 위로 이동합니다.
+    pub fn task_up(&mut self) {
+        if let Some(i) = self.tasks_state.selected() {
+            self.tasks_state.select(Some(i.saturating_sub(1)));
+        }
+    }
+
+    /// 할 일 패널 커서를 아래로 이동합니다.
+    pub fn task_down(&mut self) {
+        let count = self.pending_task_indices().len();
+        if let Some(i) = self.tasks_state.selected()
+            && i + 1 < count
+        {
+            self.tasks_state.select(Some(i + 1));
+        }
+    }
+
+    /// 선택된 할 일을 위(`-1`) 또는 아래(`1`)의 할 일과 자리를 바꾸고 파일에 저장합니다.
+    pub fn move_selected_task(&mut self, offset: isize) {
+        let indices = self.pending_task_indices();
+        let Some(selected) = self.tasks_state.selected() else {
+            return;
+        };
+        let Some(target) = selected.checked_add_signed(offset) else {
+            return;
+        };
+        if target >= indices.len() {
+            return;
+        }
+
+        let a = self.logs[indices[selected]].clone();
+        let b = self.logs[indices[target]].clone();
+        if storage::swap_entries(&a, &b).is_ok() {
+            self.update_logs();
+            self.tasks_state.select(Some(target));
+        }
+    }
+
+    /// 선택된 할 일이 작성된 로그 항목으로 로그 패널 커서를 옮기고 포커스를 넘깁니다.
+    pub fn jump_to_selected_task(&mut self) {
+        if let Some(i) = self.selected_task_index() {
+            self.logs_state.select(Some(i));
+            self.focus = FocusPane::Logs;
         }
     }
 
@@ -245,6 +1647,7 @@ impl<'a> App<'a> {
 
     /// 애플리케이션 종료 플래그를 설정합니다. 메인 루프가 이를 감지하여 종료합니다.
     pub fn quit(&mut self) {
+        self.stop_todo_timer(); // 진행 중이던 작업 시간은 기록하고 종료
         self.should_quit = true;
     }
 
@@ -261,6 +1664,8 @@ impl<'a> App<'a> {
                     .set_placeholder_text(&self.config.placeholders.navigate);
             }
             InputMode::Editing => {
+                self.focus = FocusPane::Logs;
+                self.tag_suggestions.clear();
                 self.textarea
                     .set_placeholder_text(&self.config.placeholders.editing);
                 // 검색 결과 화면에서 편집으로 넘어갈 때 전체 로그로 복귀
@@ -277,3 +1682,237 @@ impl<'a> App<'a> {
         self.input_mode = mode;
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use std::path::PathBuf;
+
+    /// 테스트용 로그 폴더. 테스트가 실패해도 지워집니다.
+    struct TempLogDir(PathBuf);
+
+    impl TempLogDir {
+        fn new(name: &str) -> Self {
+            let dir =
+                std::env::temp_dir().join(format!("sonomemo-app-{}-{}", name, std::process::id()));
+            let _ = fs::remove_dir_all(&dir);
+            fs::create_dir_all(&dir).unwrap();
+            TempLogDir(dir)
+        }
+
+        fn config(&self) -> Config {
+            let mut config = Config::default();
+            config.data.log_path = self.0.to_str().unwrap().to_string();
+            config
+        }
+
+        fn today_file(&self) -> PathBuf {
+            self.0.join(format!(
+                "{}.md",
+                Local::now().date_naive().format("%Y-%m-%d")
+            ))
+        }
+
+        fn write_today(&self, content: &str) {
+            fs::write(self.today_file(), content).unwrap();
+        }
+    }
+
+    impl Drop for TempLogDir {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.0);
+        }
+    }
+
+    fn entries(lines: &[&str]) -> Vec<LogEntry> {
+        lines
+            .iter()
+            .enumerate()
+            .map(|(i, line)| LogEntry {
+                content: line.to_string(),
+                file_path: "2025-01-10.md".to_string(),
+                line_number: i,
+            })
+            .collect()
+    }
+
+    fn at(time: &str) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(2025, 1, 10)
+            .unwrap()
+            .and_time(NaiveTime::parse_from_str(time, "%H:%M:%S").unwrap())
+    }
+
+    #[test]
+    fn test_replay_pomodoro_pause_resume_extend() {
+        let date = NaiveDate::from_ymd_opt(2025, 1, 10).unwrap();
+        let mut log = vec![
+            "[09:00:00] Pomodoro: 25m started #old",
+            "[09:05:00] Interruption: internal 지난 세션",
+            "[09:25:00] Pomodoro: completed int:1 ext:0",
+            "[10:00:00] Pomodoro: 25m started #deep",
+            "[10:05:00] Interruption: external 전화",
+            "[10:10:00] Pomodoro: paused",
+            "[10:12:00] Pomodoro: extended +5m",
+        ];
+
+        // 일시정지 중 연장: 남은 시간 = 10:25 + 5분 - 10:10
+        let replay = replay_pomodoro(&entries(&log), date).unwrap();
+        assert_eq!(replay.phase, PomodoroPhase::Work);
+        assert_eq!(replay.tags, vec!["#deep".to_string()]);
+        assert_eq!(replay.paused_at, Some(at("10:10:00")));
+        assert_eq!(replay.end - at("10:10:00"), chrono::Duration::minutes(20));
+        // 이번 세션의 방해만 이어받음
+        assert_eq!(
+            replay.interruptions,
+            vec![Interruption {
+                external: true,
+                text: "전화".to_string(),
+            }]
+        );
+
+        // 재개하면 멈춰 있던 시간만큼 끝나는 시각이 밀림
+        log.push("[10:20:00] Pomodoro: resumed");
+        let replay = replay_pomodoro(&entries(&log), date).unwrap();
+        assert_eq!(replay.paused_at, None);
+        assert_eq!(replay.end, at("10:40:00"));
+
+        log.push("[10:30:00] Pomodoro: extended +10m");
+        let replay = replay_pomodoro(&entries(&log), date).unwrap();
+        assert_eq!(replay.end, at("10:50:00"));
+
+        // 끝났거나 중단된 타이머는 복원하지 않음
+        log.push("[10:50:00] Pomodoro: completed int:0 ext:1");
+        assert!(replay_pomodoro(&entries(&log), date).is_none());
+        log.push("[11:00:00] Pomodoro: 5m short-break started");
+        log.push("[11:01:00] Pomodoro: stopped");
+        assert!(replay_pomodoro(&entries(&log), date).is_none());
+    }
+
+    #[test]
+    fn test_count_completed_work_sessions() {
+        let log = entries(&[
+            "[09:00:00] Pomodoro: 25m started #deep",
+            "[09:25:00] Pomodoro: completed",
+            "[09:25:00] Pomodoro: 5m short-break started",
+            "[09:30:00] Pomodoro: completed",
+            "[09:30:00] Pomodoro: 25m started",
+            "[09:40:00] Pomodoro: stopped",
+            "[10:00:00] Pomodoro: 25m started",
+            "[10:10:00] Pomodoro: paused",
+            "[10:12:00] Pomodoro: resumed",
+            "[10:27:00] Pomodoro: completed int:1 ext:0",
+            "[10:30:00] Pomodoro: completed",
+        ]);
+        // 휴식, 중단한 집중, 시작 기록 없는 완료는 세지 않음
+        assert_eq!(count_completed_work_sessions(&log), 2);
+    }
+
+    #[test]
+    fn test_accept_tag_suggestion_replaces_whole_tag() {
+        let dir = TempLogDir::new("tag-suggestion");
+        let mut app = App::with_config(dir.config());
+        let mut accept = |line: &str, col: u16| {
+            app.textarea = TextArea::from(vec![line.to_string()]);
+            app.textarea.move_cursor(CursorMove::Jump(0, col));
+            app.tag_suggestions = vec!["#work".to_string()];
+            app.tag_suggestion_index = 0;
+            app.accept_tag_suggestion();
+            (app.textarea.lines()[0].clone(), app.textarea.cursor().1)
+        };
+
+        // 커서 뒤에 남은 태그 글자도 지우고, 이미 있는 공백은 재사용
+        assert_eq!(accept("회의 #wrk 끝", 5), ("회의 #work 끝".to_string(), 9));
+        assert_eq!(accept("회의 #wo", 6), ("회의 #work ".to_string(), 9));
+        assert_eq!(accept("#w끝", 2), ("#work ".to_string(), 6));
+    }
+
+    #[test]
+    fn test_checkin_slot_before() {
+        let times: Vec<String> = ["13:30", " 09:30 ", "18:00", "lunch"]
+            .iter()
+            .map(|t| t.to_string())
+            .collect();
+        let time = |t: &str| NaiveTime::parse_from_str(t, "%H:%M").unwrap();
+
+        assert_eq!(checkin_slot_before(&times, time("08:00")), None);
+        assert_eq!(
+            checkin_slot_before(&times, time("12:00")),
+            Some(time("09:30"))
+        );
+        assert_eq!(
+            checkin_slot_before(&times, time("18:00")),
+            Some(time("18:00"))
+        );
+        assert_eq!(checkin_slot_before(&[], time("23:59")), None);
+    }
+
+    #[test]
+    fn test_finish_checkin() {
+        let dir = TempLogDir::new("checkin");
+        let mut app = App::with_config(dir.config());
+        let mood = app.config.moods[1].clone();
+
+        // 에너지만 매기고 집중도는 건너뜀
+        app.open_checkin();
+        app.mood_list_state.select(Some(1));
+        app.finish_checkin(Some(4), None);
+        assert!(!app.show_mood_popup);
+        assert_eq!(
+            app.latest_checkin(),
+            Some(Checkin {
+                mood_id: Some(mood.id.clone()),
+                energy: Some(4),
+                focus: None,
+            })
+        );
+
+        // 기분을 고르지 않았으면 기록하지 않음
+        app.open_checkin();
+        app.mood_list_state.select(None);
+        app.finish_checkin(Some(2), Some(2));
+        assert!(!app.show_mood_popup);
+        let log = fs::read_to_string(dir.today_file()).unwrap();
+        assert_eq!(log.lines().filter(|l| l.contains("Mood: ")).count(), 1);
+        assert!(log.contains(&format!("mood:{} energy:4\n", mood.id)));
+    }
+
+    #[test]
+    fn test_restore_paused_pomodoro() {
+        let dir = TempLogDir::new("restore-paused");
+        dir.write_today(
+            "[00:00:00] Pomodoro: 25m started #deep\n\
+             [00:00:00] Interruption: internal 메일 확인\n\
+             [00:00:00] Pomodoro: paused\n\
+             [00:00:00] Pomodoro: extended +5m\n",
+        );
+
+        let app = App::with_config(dir.config());
+        assert_eq!(app.pomodoro_end, None);
+        assert_eq!(app.pomodoro_paused, Some(chrono::Duration::minutes(30)));
+        assert_eq!(app.pomodoro_phase, PomodoroPhase::Work);
+        assert_eq!(app.pomodoro_tags, vec!["#deep".to_string()]);
+        assert_eq!(app.interruption_counts(), (1, 0));
+        assert_eq!(app.pomodoro_session, 1);
+        assert!(!app.show_siren_popup);
+    }
+
+    #[test]
+    fn test_restore_expired_pomodoro() {
+        let dir = TempLogDir::new("restore-expired");
+        // 자정에 시작한 0분 타이머는 테스트가 언제 돌든 이미 끝나 있음
+        dir.write_today(
+            "[00:00:00] Pomodoro: 0m started #deep\n\
+             [00:00:00] Interruption: external 전화\n",
+        );
+
+        let app = App::with_config(dir.config());
+        // 꺼져 있는 동안 끝난 단계는 완료로 기록하고, 다음 단계는 묻기만 함
+        assert!(!app.pomodoro_active());
+        assert!(app.show_siren_popup);
+        assert_eq!(app.completed_work_sessions(), 1);
+        assert_eq!(app.pomodoro_next, Some(PomodoroPhase::ShortBreak));
+        let log = fs::read_to_string(dir.today_file()).unwrap();
+        assert!(log.ends_with("Pomodoro: completed int:0 ext:1\n"));
+    }
+}
@@ -32,10 +32,14 @@ fn is_match(key: &KeyEvent, binding: &str) -> bool {
             "esc" => target_code = KeyCode::Esc,
             "backspace" => target_code = KeyCode::Backspace,
             "tab" => target_code = KeyCode::Tab,
+            "backtab" => target_code = KeyCode::BackTab,
             "up" => target_code = KeyCode::Up,
             "down" => target_code = KeyCode::Down,
             "left" => target_code = KeyCode::Left,
             "right" => target_code = KeyCode::Right,
+            "delete" | "del" => target_code = KeyCode::Delete,
+            // '+'는 조합 구분자라서 이름으로 적습니다 (Shift+= 도 `Char('+')`로 들어옴)
+            "plus" => target_code = KeyCode::Char('+'),
             // Handle single characters and other keys
             c if c.chars().count() == 1 => {
                 if let Some(ch) = c.chars().next() {
@@ -91,6 +95,89 @@ pub struct Config {
     pub theme: Theme,
     #[serde(default)]
     pub data: DataConfig,
+    #[serde(default)]
+    pub recurring: RecurringConfig,
+    #[serde(default)]
+    pub reminder: ReminderConfig,
+    #[serde(default)]
+    pub pomodoro: PomodoroConfig,
+    #[serde(default)]
+    pub alert: AlertConfig,
+    #[serde(default)]
+    pub nudge: NudgeConfig,
+    #[serde(default)]
+    pub timers: TimersConfig,
+    #[serde(default)]
+    pub daemon: DaemonConfig,
+    #[serde(default)]
+    pub moods: MoodList,
+    #[serde(default)]
+    pub checkin: CheckinConfig,
+    #[serde(default)]
+    pub goals: GoalsConfig,
+    #[serde(default)]
+    pub history: HistoryConfig,
+}
+
+/// 기분/에너지/집중도 체크인 일정입니다.
+#[derive(Debug, Deserialize, Serialize, Clone, Default)]
+pub struct CheckinConfig {
+    /// 체크인을 물어볼 시각들 (예: ["09:30", "13:30", "18:00"])
+    #[serde(default)]
+    pub times: Vec<String>,
+}
+
+/// 기분 선택지 하나입니다. `id`는 로그에 `mood:<id>`로 저장되므로 이름(label)을 바꿔도 기록이 유지됩니다.
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct MoodConfig {
+    pub id: String,
+    pub emoji: String,
+    pub label: String,
+    /// 색상 (비워두면 `theme.mood`)
+    #[serde(default)]
+    pub color: String,
+    /// 기분의 좋고 나쁨을 나타내는 점수 (예: -2 ~ 2). 통계에 사용됩니다.
+    #[serde(default)]
+    pub valence: Option<i32>,
+}
+
+/// `[[moods]]` 목록입니다. 설정하지 않으면 기본 다섯 가지 기분을 사용합니다.
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(transparent)]
+pub struct MoodList(pub Vec<MoodConfig>);
+
+impl Default for MoodList {
+    fn default() -> Self {
+        let mood = |id: &str, emoji: &str, label: &str, color: &str, valence| MoodConfig {
+            id: id.to_string(),
+            emoji: emoji.to_string(),
+            label: label.to_string(),
+            color: color.to_string(),
+            valence: Some(valence),
+        };
+        Self(vec![
+            mood("happy", "😊", "조음", "Yellow", 2),
+            mood("neutral", "😐", "걍그럼", "Gray", 0),
+            mood("stressed", "😫", "구림", "Red", -2),
+            mood("focused", "🧐", "집중", "Cyan", 1),
+            mood("tired", "😴", "피곤", "Blue", -1),
+        ])
+    }
+}
+
+impl MoodList {
+    /// id로 기분을 찾습니다.
+    pub fn find(&self, id: &str) -> Option<&MoodConfig> {
+        self.0.iter().find(|mood| mood.id == id)
+    }
+}
+
+impl std::ops::Deref for MoodList {
+    type Target = [MoodConfig];
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
 }
 
 /// 데이터 관련 설정입니다 (예: 로그 저장 경로).
@@ -99,6 +186,257 @@ pub struct DataConfig {
     pub log_path: String,
 }
 
+/// 반복 할 일 설정입니다.
+/// 각 항목은 `every:` 토큰을 포함한 할 일 내용입니다 (예: "물 마시기 every:day").
+#[derive(Debug, Deserialize, Serialize, Clone, Default)]
+pub struct RecurringConfig {
+    #[serde(default)]
+    pub tasks: Vec<String>,
+}
+
+/// 할 일 시각 알림(`remind:`) 설정입니다.
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct ReminderConfig {
+    /// 다시 알림(snooze) 시 미룰 시간 (분)
+    #[serde(default = "default_snooze_minutes")]
+    pub snooze_minutes: i64,
+    /// 알림이 뜰 때 터미널 벨을 울릴지 여부
+    #[serde(default = "default_true")]
+    pub bell: bool,
+}
+
+fn default_snooze_minutes() -> i64 {
+    10
+}
+fn default_true() -> bool {
+    true
+}
+
+impl Default for ReminderConfig {
+    fn default() -> Self {
+        Self {
+            snooze_minutes: default_snooze_minutes(),
+            bell: default_true(),
+        }
+    }
+}
+
+/// 이름 붙은 타이머/스톱워치 설정입니다.
+#[derive(Debug, Deserialize, Serialize, Clone, Default)]
+pub struct TimersConfig {
+    /// 타이머를 시작하고 끝낼 때 로그에 기록할지 여부 (`Timer: 빨래 40m started`)
+    #[serde(default)]
+    pub log: bool,
+}
+
+/// 활동 그래프에서 지난 날짜를 열어볼 때의 설정입니다.
+#[derive(Debug, Deserialize, Serialize, Clone, Default)]
+pub struct HistoryConfig {
+    /// 켜면 지난 날짜에도 메모를 추가하고 할 일을 체크할 수 있습니다. (기본: 읽기 전용)
+    #[serde(default)]
+    pub editable: bool,
+}
+
+/// 하루 목표와 달성 축하 설정입니다. 목표 값이 0이면 그 목표는 쓰지 않습니다.
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct GoalsConfig {
+    /// 하루에 완료할 할 일 수
+    #[serde(default)]
+    pub todos_done: usize,
+    /// 하루에 남길 로그 항목 수
+    #[serde(default)]
+    pub entries: usize,
+    /// 하루에 마칠 집중 세션 수
+    #[serde(default)]
+    pub pomodoros: usize,
+    /// 축하 방식: `"popup"`(폭죽 팝업) | `"toast"`(알림 한 줄) | `"none"`
+    #[serde(default = "default_celebration")]
+    pub celebration: String,
+}
+
+fn default_celebration() -> String {
+    "popup".to_string()
+}
+
+impl Default for GoalsConfig {
+    fn default() -> Self {
+        Self {
+            todos_done: 0,
+            entries: 0,
+            pomodoros: 0,
+            celebration: default_celebration(),
+        }
+    }
+}
+
+/// 앱을 닫아도 알림이 울리게 하는 백그라운드 데몬(`sonomemo daemon`) 설정입니다.
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct DaemonConfig {
+    /// 켜면 앱이 뽀모도로/타이머를 데몬에도 등록합니다.
+    #[serde(default)]
+    pub enabled: bool,
+    /// 데몬이 요청을 받는 유닉스 소켓 경로
+    #[serde(default = "default_socket_path")]
+    pub socket_path: String,
+    /// 알림 시 실행할 명령 (`sh -c`, 메시지는 `$SONOMEMO_MESSAGE`). 비워두면 실행하지 않음
+    #[serde(default)]
+    pub command: String,
+}
+
+fn default_socket_path() -> String {
+    "sonomemo.sock".to_string()
+}
+
+impl Default for DaemonConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            socket_path: default_socket_path(),
+            command: String::new(),
+        }
+    }
+}
+
+/// 시간 감각을 되찾게 도와주는 주기 알림과 과몰입 경고 설정입니다.
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct NudgeConfig {
+    /// 정각 기준 이 간격(분)마다 현재 시각을 알려줍니다. (0이면 끔, 예: 30, 60)
+    #[serde(default)]
+    pub chime_minutes: i64,
+    /// 쉬지 않고 이만큼(분) 활동하면 휴식을 권합니다. (0이면 끔)
+    #[serde(default)]
+    pub hyperfocus_minutes: i64,
+    /// 이 시간(분) 이상 입력이 없으면 쉰 것으로 봅니다.
+    #[serde(default = "default_break_minutes")]
+    pub break_minutes: i64,
+    /// 알림을 띄우지 않을 시간대 (예: ["22:00-08:00", "12:00-13:00"])
+    #[serde(default)]
+    pub quiet_hours: Vec<String>,
+    /// 알림이 뜰 때 터미널 벨을 울릴지 여부
+    #[serde(default)]
+    pub bell: bool,
+}
+
+fn default_break_minutes() -> i64 {
+    5
+}
+
+impl Default for NudgeConfig {
+    fn default() -> Self {
+        Self {
+            chime_minutes: 0,
+            hyperfocus_minutes: 0,
+            break_minutes: default_break_minutes(),
+            quiet_hours: Vec::new(),
+            bell: false,
+        }
+    }
+}
+
+/// 뽀모도로 사이클(집중/짧은 휴식/긴 휴식) 설정입니다.
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct PomodoroConfig {
+    /// 집중 시간 (분)
+    #[serde(default = "default_work_minutes")]
+    pub work_minutes: i64,
+    /// 짧은 휴식 시간 (분)
+    #[serde(default = "default_short_break_minutes")]
+    pub short_break_minutes: i64,
+    /// 긴 휴식 시간 (분)
+    #[serde(default = "default_long_break_minutes")]
+    pub long_break_minutes: i64,
+    /// 몇 번째 집중마다 긴 휴식을 할지
+    #[serde(default = "default_long_break_every")]
+    pub long_break_every: usize,
+    /// 한 단계가 끝나면 묻지 않고 바로 다음 단계를 시작할지 여부
+    #[serde(default)]
+    pub auto_advance: bool,
+    /// 연장 키를 누를 때마다 늘어나는 시간 (분)
+    #[serde(default = "default_extend_minutes")]
+    pub extend_minutes: i64,
+}
+
+fn default_work_minutes() -> i64 {
+    25
+}
+fn default_short_break_minutes() -> i64 {
+    5
+}
+fn default_long_break_minutes() -> i64 {
+    15
+}
+fn default_long_break_every() -> usize {
+    4
+}
+fn default_extend_minutes() -> i64 {
+    5
+}
+
+impl Default for PomodoroConfig {
+    fn default() -> Self {
+        Self {
+            work_minutes: default_work_minutes(),
+            short_break_minutes: default_short_break_minutes(),
+            long_break_minutes: default_long_break_minutes(),
+            long_break_every: default_long_break_every(),
+            auto_advance: false,
+            extend_minutes: default_extend_minutes(),
+        }
+    }
+}
+
+/// 타이머 종료 알림(사이렌 팝업, 벨, 데스크톱 알림) 설정입니다.
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct AlertConfig {
+    /// 알림이 뜬 뒤 입력을 막는 시간 (초). 0이면 바로 닫을 수 있습니다.
+    #[serde(default = "default_block_seconds")]
+    pub block_seconds: i64,
+    /// 알림이 뜰 때 터미널 벨을 울리는 횟수 (0이면 울리지 않음)
+    #[serde(default)]
+    pub bell_count: usize,
+    /// 데스크톱 알림 방식: "none", "osc9", "osc777"
+    #[serde(default = "default_desktop_notification")]
+    pub desktop_notification: String,
+    /// 알림 팝업을 깜빡이게 할지 여부
+    #[serde(default = "default_true")]
+    pub blink: bool,
+    /// 알림 팝업에 표시할 아스키 아트/문구 (한 줄씩)
+    #[serde(default = "default_alert_art")]
+    pub art: Vec<String>,
+}
+
+fn default_block_seconds() -> i64 {
+    5
+}
+fn default_desktop_notification() -> String {
+    "none".to_string()
+}
+fn default_alert_art() -> Vec<String> {
+    [
+        "         _______  TIME'S UP!  _______",
+        "        /       \\            /       \\",
+        "       |  (o)  |   🚨🚨🚨   |  (o)  |",
+        "        \\_______/            \\_______/",
+        "",
+        "      Take a break! Stretch! Drink water!",
+    ]
+    .iter()
+    .map(|line| line.to_string())
+    .collect()
+}
+
+impl Default for AlertConfig {
+    fn default() -> Self {
+        Self {
+            block_seconds: default_block_seconds(),
+            bell_count: 0,
+            desktop_notification: default_desktop_notification(),
+            blink: default_true(),
+            art: default_alert_art(),
+        }
+    }
+}
+
 /// UI의 입력 필드에 표시될 플레이스홀더 텍스트 설정입니다.
 #[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct Placeholders {
@@ -126,6 +464,18 @@ pub struct KeyBindings {
     pub search: SearchBindings,
     #[serde(default)]
     pub popup: PopupBindings,
+    #[serde(default)]
+    pub reminder: ReminderBindings,
+    #[serde(default)]
+    pub interruption: InterruptionBindings,
+    #[serde(default)]
+    pub timers: TimersBindings,
+    #[serde(default)]
+    pub activity: ActivityBindings,
+    #[serde(default)]
+    pub hour: HourBindings,
+    #[serde(default)]
+    pub tags: TagsBindings,
 }
 
 /// 'Navigate' (기본 탐색) 모드에서의 키 바인딩입니다.
@@ -145,6 +495,34 @@ pub struct NavigateBindings {
     pub prev_todo: Vec<String>,
     #[serde(default = "default_copy")]
     pub copy: Vec<String>,
+    #[serde(default = "default_switch_pane")]
+    pub switch_pane: Vec<String>,
+    #[serde(default = "default_move_task_up")]
+    pub move_task_up: Vec<String>,
+    #[serde(default = "default_move_task_down")]
+    pub move_task_down: Vec<String>,
+    #[serde(default = "default_jump_to_log")]
+    pub jump_to_log: Vec<String>,
+    #[serde(default = "default_focus_todo")]
+    pub focus_todo: Vec<String>,
+    #[serde(default = "default_pomodoro_extend")]
+    pub pomodoro_extend: Vec<String>,
+    #[serde(default = "default_pomodoro_cancel")]
+    pub pomodoro_cancel: Vec<String>,
+    #[serde(default = "default_interrupt")]
+    pub interrupt: Vec<String>,
+    #[serde(default = "default_pomodoro_stats")]
+    pub pomodoro_stats: Vec<String>,
+    #[serde(default = "default_todo_timer")]
+    pub todo_timer: Vec<String>,
+    #[serde(default = "default_timers")]
+    pub timers: Vec<String>,
+    #[serde(default = "default_checkin")]
+    pub checkin: Vec<String>,
+    #[serde(default = "default_mood_trend")]
+    pub mood_trend: Vec<String>,
+    #[serde(default = "default_hour_stats")]
+    pub hour_stats: Vec<String>,
 }
 
 fn default_next_todo() -> Vec<String> {
@@ -156,6 +534,48 @@ fn default_prev_todo() -> Vec<String> {
 fn default_copy() -> Vec<String> {
     vec!["y".to_string(), "ㅛ".to_string()]
 }
+fn default_switch_pane() -> Vec<String> {
+    vec!["tab".to_string()]
+}
+fn default_move_task_up() -> Vec<String> {
+    vec!["shift+up".to_string()]
+}
+fn default_move_task_down() -> Vec<String> {
+    vec!["shift+down".to_string()]
+}
+fn default_jump_to_log() -> Vec<String> {
+    vec!["o".to_string(), "ㅐ".to_string()]
+}
+fn default_focus_todo() -> Vec<String> {
+    vec!["f".to_string(), "ㄹ".to_string()]
+}
+fn default_pomodoro_extend() -> Vec<String> {
+    vec!["plus".to_string(), "=".to_string()]
+}
+fn default_pomodoro_cancel() -> Vec<String> {
+    vec!["x".to_string(), "ㅌ".to_string()]
+}
+fn default_interrupt() -> Vec<String> {
+    vec!["!".to_string()]
+}
+fn default_pomodoro_stats() -> Vec<String> {
+    vec!["s".to_string(), "ㄴ".to_string()]
+}
+fn default_todo_timer() -> Vec<String> {
+    vec!["w".to_string(), "ㅈ".to_string()]
+}
+fn default_timers() -> Vec<String> {
+    vec!["m".to_string(), "ㅡ".to_string()]
+}
+fn default_checkin() -> Vec<String> {
+    vec!["c".to_string(), "ㅊ".to_string()]
+}
+fn default_mood_trend() -> Vec<String> {
+    vec!["v".to_string(), "ㅍ".to_string()]
+}
+fn default_hour_stats() -> Vec<String> {
+    vec!["h".to_string(), "ㅗ".to_string()]
+}
 
 /// 'Editing' (작성/수정) 모드에서의 키 바인딩입니다.
 #[derive(Debug, Deserialize, Serialize, Clone)]
@@ -163,6 +583,31 @@ pub struct EditingBindings {
     pub save: Vec<String>,    // Enter
     pub newline: Vec<String>, // Shift+Enter
     pub cancel: Vec<String>,  // Esc
+    /// 태그 자동완성 후보 선택
+    #[serde(default = "default_tag_accept")]
+    pub tag_accept: Vec<String>,
+    /// 태그 자동완성 후보 닫기
+    #[serde(default = "default_tag_dismiss")]
+    pub tag_dismiss: Vec<String>,
+    /// 이전 태그 후보로 이동
+    #[serde(default = "default_tag_prev")]
+    pub tag_prev: Vec<String>,
+    /// 다음 태그 후보로 이동
+    #[serde(default = "default_tag_next")]
+    pub tag_next: Vec<String>,
+}
+
+fn default_tag_accept() -> Vec<String> {
+    vec!["tab".to_string(), "enter".to_string()]
+}
+fn default_tag_dismiss() -> Vec<String> {
+    vec!["esc".to_string()]
+}
+fn default_tag_prev() -> Vec<String> {
+    vec!["up".to_string()]
+}
+fn default_tag_next() -> Vec<String> {
+    vec!["down".to_string()]
 }
 
 /// 'Search' (검색) 모드에서의 키 바인딩입니다.
@@ -179,6 +624,199 @@ pub struct PopupBindings {
     pub cancel: Vec<String>,
     pub up: Vec<String>,
     pub down: Vec<String>,
+    #[serde(default = "default_discard")]
+    pub discard: Vec<String>,
+    /// 체크인 점수를 바로 고르는 키. n번째 키가 n점입니다. (최대 5개)
+    #[serde(default = "default_ratings")]
+    pub ratings: Vec<String>,
+}
+
+fn default_discard() -> Vec<String> {
+    vec!["d".to_string(), "delete".to_string(), "ㅇ".to_string()]
+}
+fn default_ratings() -> Vec<String> {
+    (1..=5).map(|n| n.to_string()).collect()
+}
+
+/// 알림 팝업에서의 키 바인딩입니다.
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct ReminderBindings {
+    pub dismiss: Vec<String>,
+    pub snooze: Vec<String>,
+}
+
+/// 방해 기록 팝업에서의 키 바인딩입니다. 문자 키는 모두 입력으로 쓰입니다.
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct InterruptionBindings {
+    #[serde(default = "default_interruption_submit")]
+    pub submit: Vec<String>,
+    #[serde(default = "default_interruption_cancel")]
+    pub cancel: Vec<String>,
+    /// 내부(💭) / 외부(📞) 방해 전환
+    #[serde(default = "default_interruption_toggle_kind")]
+    pub toggle_kind: Vec<String>,
+}
+
+fn default_interruption_submit() -> Vec<String> {
+    vec!["enter".to_string()]
+}
+fn default_interruption_cancel() -> Vec<String> {
+    vec!["esc".to_string()]
+}
+fn default_interruption_toggle_kind() -> Vec<String> {
+    vec!["tab".to_string()]
+}
+
+/// 타이머 목록 팝업에서의 키 바인딩입니다. 문자 키는 모두 입력으로 쓰입니다.
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct TimersBindings {
+    /// 입력한 타이머/스톱워치 추가
+    #[serde(default = "default_timers_add")]
+    pub add: Vec<String>,
+    /// 선택한 타이머 정지
+    #[serde(default = "default_timers_remove")]
+    pub remove: Vec<String>,
+    #[serde(default = "default_timers_close")]
+    pub close: Vec<String>,
+    #[serde(default = "default_timers_up")]
+    pub up: Vec<String>,
+    #[serde(default = "default_timers_down")]
+    pub down: Vec<String>,
+}
+
+fn default_timers_add() -> Vec<String> {
+    vec!["enter".to_string()]
+}
+fn default_timers_remove() -> Vec<String> {
+    vec!["delete".to_string()]
+}
+fn default_timers_close() -> Vec<String> {
+    vec!["esc".to_string()]
+}
+fn default_timers_up() -> Vec<String> {
+    vec!["up".to_string()]
+}
+fn default_timers_down() -> Vec<String> {
+    vec!["down".to_string()]
+}
+
+/// 활동 히트맵 팝업에서의 키 바인딩입니다.
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct ActivityBindings {
+    #[serde(default = "default_activity_prev_year")]
+    pub prev_year: Vec<String>,
+    #[serde(default = "default_activity_next_year")]
+    pub next_year: Vec<String>,
+    #[serde(default = "default_activity_prev_week")]
+    pub prev_week: Vec<String>,
+    #[serde(default = "default_activity_next_week")]
+    pub next_week: Vec<String>,
+    #[serde(default = "default_activity_prev_day")]
+    pub prev_day: Vec<String>,
+    #[serde(default = "default_activity_next_day")]
+    pub next_day: Vec<String>,
+    /// 커서가 가리키는 날의 로그를 메인 화면에 열기
+    #[serde(default = "default_activity_open_day")]
+    pub open_day: Vec<String>,
+    #[serde(default = "default_activity_next_metric")]
+    pub next_metric: Vec<String>,
+    #[serde(default = "default_activity_prev_metric")]
+    pub prev_metric: Vec<String>,
+    /// 지표를 바로 고르는 키. n번째 키가 n번째 지표입니다.
+    #[serde(default = "default_activity_metrics")]
+    pub metrics: Vec<String>,
+    #[serde(default = "default_activity_close")]
+    pub close: Vec<String>,
+}
+
+fn default_activity_prev_year() -> Vec<String> {
+    vec!["left".to_string()]
+}
+fn default_activity_next_year() -> Vec<String> {
+    vec!["right".to_string()]
+}
+fn default_activity_prev_week() -> Vec<String> {
+    vec!["h".to_string(), "ㅗ".to_string()]
+}
+fn default_activity_next_week() -> Vec<String> {
+    vec!["l".to_string(), "ㅣ".to_string()]
+}
+fn default_activity_prev_day() -> Vec<String> {
+    vec!["k".to_string(), "ㅏ".to_string(), "up".to_string()]
+}
+fn default_activity_next_day() -> Vec<String> {
+    vec!["j".to_string(), "ㅓ".to_string(), "down".to_string()]
+}
+fn default_activity_open_day() -> Vec<String> {
+    vec!["enter".to_string()]
+}
+fn default_activity_next_metric() -> Vec<String> {
+    vec!["tab".to_string()]
+}
+fn default_activity_prev_metric() -> Vec<String> {
+    vec!["backtab".to_string()]
+}
+fn default_activity_metrics() -> Vec<String> {
+    (1..=6).map(|n| n.to_string()).collect()
+}
+fn default_activity_close() -> Vec<String> {
+    vec![
+        "esc".to_string(),
+        "q".to_string(),
+        "g".to_string(),
+        "ㅎ".to_string(),
+    ]
+}
+
+/// 시간대 분포 팝업에서의 키 바인딩입니다. 문자 키는 모두 태그 필터 입력으로 쓰입니다.
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct HourBindings {
+    /// 입력한 태그 필터 적용
+    #[serde(default = "default_hour_apply")]
+    pub apply: Vec<String>,
+    /// 태그 필터의 마지막 글자 지우기
+    #[serde(default = "default_hour_erase")]
+    pub erase: Vec<String>,
+    #[serde(default = "default_hour_next_metric")]
+    pub next_metric: Vec<String>,
+    #[serde(default = "default_hour_prev_metric")]
+    pub prev_metric: Vec<String>,
+    #[serde(default = "default_hour_close")]
+    pub close: Vec<String>,
+}
+
+fn default_hour_apply() -> Vec<String> {
+    vec!["enter".to_string()]
+}
+fn default_hour_erase() -> Vec<String> {
+    vec!["backspace".to_string()]
+}
+fn default_hour_next_metric() -> Vec<String> {
+    vec!["tab".to_string()]
+}
+fn default_hour_prev_metric() -> Vec<String> {
+    vec!["backtab".to_string()]
+}
+fn default_hour_close() -> Vec<String> {
+    vec!["esc".to_string()]
+}
+
+/// 태그 트리 팝업에서의 키 바인딩입니다. (이동/검색/닫기는 `popup` 바인딩)
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct TagsBindings {
+    /// 선택한 상위 태그의 하위 태그 접기
+    #[serde(default = "default_tags_collapse")]
+    pub collapse: Vec<String>,
+    /// 선택한 상위 태그의 하위 태그 펼치기
+    #[serde(default = "default_tags_expand")]
+    pub expand: Vec<String>,
+}
+
+fn default_tags_collapse() -> Vec<String> {
+    vec!["left".to_string(), "h".to_string(), "ㅗ".to_string()]
+}
+fn default_tags_expand() -> Vec<String> {
+    vec!["right".to_string(), "l".to_string(), "ㅣ".to_string()]
 }
 
 /// UI 색상 테마 설정입니다. 가능한 색상은 `tui` 크레이트의 색상 이름(예: "Red", "Blue") 혹은 RGB 값("r,g,b")입니다.
@@ -194,6 +832,37 @@ pub struct Theme {
     pub tag: String,
     pub mood: String,
     pub timestamp: String,
+    #[serde(default = "default_border_focus")]
+    pub border_focus: String,
+    #[serde(default = "default_focus")]
+    pub focus: String,
+    #[serde(default = "default_pomodoro_color")]
+    pub pomodoro: String,
+    /// 활동 히트맵의 강도별 색상 (기록 없음 → 가장 많음)
+    #[serde(default = "default_heatmap")]
+    pub heatmap: Vec<String>,
+}
+
+fn default_pomodoro_color() -> String {
+    "LightRed".to_string()
+}
+
+fn default_heatmap() -> Vec<String> {
+    vec![
+        "50,50,50".to_string(),
+        "14,68,41".to_string(),
+        "0,109,50".to_string(),
+        "38,166,65".to_string(),
+        "57,211,83".to_string(),
+    ]
+}
+
+fn default_focus() -> String {
+    "Cyan".to_string()
+}
+
+fn default_border_focus() -> String {
+    "LightCyan".to_string()
 }
 
 impl Default for DataConfig {
@@ -218,9 +887,9 @@ impl Default for HelpMessages {
     fn default() -> Self {
         Self {
             navigate:
-                " [i] Edit  [t] Tag  [?] Search  [Enter] Toggle  [p] Pomodoro  [y] Copy  [[]] Todo Nav  [g] Graph  [l] PATH  [q] Quit "
+                " [i] Edit  [t] Tag  [?] Search  [Tab] Tasks  [Enter] Toggle  [f] Focus  [w] Timer  [m] Timers  [c] Check-in  [v] Mood Trend  [h] Hours  [p] Pomodoro(Pause)  [+] Extend  [x] Cancel  [!] Interrupt  [y] Copy  [[]] Todo Nav  [g] Graph  [s] Focus Stats  [l] PATH  [q] Quit "
                     .to_string(),
-            editing: " [Esc] Navigate Mode  [Enter] Save Memo  [Shift+Enter] New Line ".to_string(),
+            editing: " [Esc] Navigate Mode  [Enter] Save Memo  [Shift+Enter] New Line  [#] Tag (Tab: Complete) ".to_string(),
             search: " [Esc] Reset Search  [Enter] Filter ".to_string(),
         }
     }
@@ -240,6 +909,20 @@ impl Default for NavigateBindings {
             next_todo: default_next_todo(),
             prev_todo: default_prev_todo(),
             copy: default_copy(),
+            switch_pane: default_switch_pane(),
+            move_task_up: default_move_task_up(),
+            move_task_down: default_move_task_down(),
+            jump_to_log: default_jump_to_log(),
+            focus_todo: default_focus_todo(),
+            pomodoro_extend: default_pomodoro_extend(),
+            pomodoro_cancel: default_pomodoro_cancel(),
+            interrupt: default_interrupt(),
+            pomodoro_stats: default_pomodoro_stats(),
+            todo_timer: default_todo_timer(),
+            timers: default_timers(),
+            checkin: default_checkin(),
+            mood_trend: default_mood_trend(),
+            hour_stats: default_hour_stats(),
         }
     }
 }
@@ -250,6 +933,10 @@ impl Default for EditingBindings {
             save: vec!["enter".to_string()],
             newline: vec!["shift+enter".to_string()],
             cancel: vec!["esc".to_string()],
+            tag_accept: default_tag_accept(),
+            tag_dismiss: default_tag_dismiss(),
+            tag_prev: default_tag_prev(),
+            tag_next: default_tag_next(),
         }
     }
 }
@@ -270,6 +957,78 @@ impl Default for PopupBindings {
             cancel: vec!["esc".to_string(), "n".to_string(), "ㅜ".to_string()],
             up: vec!["up".to_string()],
             down: vec!["down".to_string()],
+            discard: default_discard(),
+            ratings: default_ratings(),
+        }
+    }
+}
+
+impl Default for ReminderBindings {
+    fn default() -> Self {
+        Self {
+            dismiss: vec!["enter".to_string(), "esc".to_string()],
+            snooze: vec!["s".to_string(), "ㄴ".to_string()],
+        }
+    }
+}
+
+impl Default for InterruptionBindings {
+    fn default() -> Self {
+        Self {
+            submit: default_interruption_submit(),
+            cancel: default_interruption_cancel(),
+            toggle_kind: default_interruption_toggle_kind(),
+        }
+    }
+}
+
+impl Default for TimersBindings {
+    fn default() -> Self {
+        Self {
+            add: default_timers_add(),
+            remove: default_timers_remove(),
+            close: default_timers_close(),
+            up: default_timers_up(),
+            down: default_timers_down(),
+        }
+    }
+}
+
+impl Default for ActivityBindings {
+    fn default() -> Self {
+        Self {
+            prev_year: default_activity_prev_year(),
+            next_year: default_activity_next_year(),
+            prev_week: default_activity_prev_week(),
+            next_week: default_activity_next_week(),
+            prev_day: default_activity_prev_day(),
+            next_day: default_activity_next_day(),
+            open_day: default_activity_open_day(),
+            next_metric: default_activity_next_metric(),
+            prev_metric: default_activity_prev_metric(),
+            metrics: default_activity_metrics(),
+            close: default_activity_close(),
+        }
+    }
+}
+
+impl Default for HourBindings {
+    fn default() -> Self {
+        Self {
+            apply: default_hour_apply(),
+            erase: default_hour_erase(),
+            next_metric: default_hour_next_metric(),
+            prev_metric: default_hour_prev_metric(),
+            close: default_hour_close(),
+        }
+    }
+}
+
+impl Default for TagsBindings {
+    fn default() -> Self {
+        Self {
+            collapse: default_tags_collapse(),
+            expand: default_tags_expand(),
         }
     }
 }
@@ -287,6 +1046,10 @@ impl Default for Theme {
             tag: "Yellow".to_string(),
             mood: "Magenta".to_string(),
             timestamp: "Blue".to_string(),
+            border_focus: default_border_focus(),
+            focus: default_focus(),
+            pomodoro: default_pomodoro_color(),
+            heatmap: default_heatmap(),
         }
     }
 }
@@ -318,3 +1081,55 @@ impl Config {
         default_config
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(code: KeyCode, modifiers: KeyModifiers) -> KeyEvent {
+        KeyEvent::new(code, modifiers)
+    }
+
+    #[test]
+    fn test_plus_binding() {
+        let extend = default_pomodoro_extend();
+        // 터미널은 Shift+= 를 `Char('+')`(Shift 포함 또는 없이)로 보냄
+        assert!(key_match(
+            &key(KeyCode::Char('+'), KeyModifiers::NONE),
+            &extend
+        ));
+        assert!(key_match(
+            &key(KeyCode::Char('+'), KeyModifiers::SHIFT),
+            &extend
+        ));
+        assert!(key_match(
+            &key(KeyCode::Char('='), KeyModifiers::NONE),
+            &extend
+        ));
+        assert!(!key_match(
+            &key(KeyCode::Char('-'), KeyModifiers::NONE),
+            &extend
+        ));
+        assert!(key_match(
+            &key(KeyCode::Char('+'), KeyModifiers::CONTROL),
+            &["ctrl+plus".to_string()]
+        ));
+    }
+
+    #[test]
+    fn test_delete_binding() {
+        let discard = default_discard();
+        assert!(key_match(
+            &key(KeyCode::Delete, KeyModifiers::NONE),
+            &discard
+        ));
+        assert!(key_match(
+            &key(KeyCode::Char('d'), KeyModifiers::NONE),
+            &discard
+        ));
+        assert!(!key_match(
+            &key(KeyCode::Backspace, KeyModifiers::NONE),
+            &discard
+        ));
+    }
+}
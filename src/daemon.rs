@@ -0,0 +1,548 @@
+//! 앱을 닫아도 타이머와 할 일 알림이 울리도록 하는 백그라운드 데몬(`sonomemo daemon`)입니다.
+//!
+//! 데몬은 유닉스 소켓에서 한 줄짜리 텍스트 요청을 받고, 응답을 보낸 뒤 연결을 닫습니다.
+//! TUI와 `sonomemo timer ...` CLI는 모두 이 프로토콜의 클라이언트입니다.
+//!
+//! | 요청 | 응답 |
+//! |:--- |:--- |
+//! | `PING` | `PONG` |
+//! | `ADD <키> <유닉스 시각> <메시지>` | `OK` (같은 키가 있으면 교체) |
+//! | `CANCEL <키>` | `OK` 또는 `ERR ...` |
+//! | `LIST` | `TIMER <키> <유닉스 시각> <메시지>` 줄들 + `OK` |
+//! | `REGISTER <tty 경로>` | `OK` (알림을 받을 터미널 등록) |
+//! | `ATTACH <tty 경로> <pid>` | `OK` (TUI가 뜬 터미널 등록, 그 프로세스가 살아 있는 동안은 건너뜀) |
+//! | `DETACH <tty 경로>` | `OK` (TUI가 닫힘, 이후로는 데몬이 울림) |
+
+use crate::config::Config;
+use crate::models::Reminder;
+use chrono::NaiveDate;
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::io;
+use std::time::{Duration, SystemTime};
+
+/// 요청 한 줄을 기다리는 최대 시간. 응답이 없는 연결이 데몬이나 앱을 멈추지 않게 합니다.
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// 데몬이 받는 요청입니다.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Request {
+    Ping,
+    Add {
+        key: String,
+        due: i64,
+        message: String,
+    },
+    Cancel(String),
+    List,
+    Register(String),
+    Attach {
+        tty: String,
+        pid: u32,
+    },
+    Detach(String),
+}
+
+impl Request {
+    /// 한 줄짜리 요청을 해석합니다. 잘못된 요청이면 응답으로 보낼 오류 문구를 반환합니다.
+    pub fn parse(line: &str) -> Result<Self, String> {
+        let line = line.trim();
+        let (command, rest) = line.split_once(' ').unwrap_or((line, ""));
+        let rest = rest.trim();
+
+        match command.to_uppercase().as_str() {
+            "PING" => Ok(Request::Ping),
+            "LIST" => Ok(Request::List),
+            "ADD" => {
+                let mut parts = rest.splitn(3, ' ');
+                let key = parts.next().filter(|k| !k.is_empty());
+                let due = parts.next().and_then(|d| d.parse().ok());
+                match (key, due) {
+                    (Some(key), Some(due)) => Ok(Request::Add {
+                        key: key.to_string(),
+                        due,
+                        message: parts.next().unwrap_or("").trim().to_string(),
+                    }),
+                    _ => Err("usage: ADD <key> <unix-time> <message>".to_string()),
+                }
+            }
+            "CANCEL" if !rest.is_empty() => Ok(Request::Cancel(rest.to_string())),
+            "CANCEL" => Err("usage: CANCEL <key>".to_string()),
+            "REGISTER" if !rest.is_empty() => Ok(Request::Register(rest.to_string())),
+            "REGISTER" => Err("usage: REGISTER <tty>".to_string()),
+            "ATTACH" => match rest.rsplit_once(' ') {
+                Some((tty, pid)) if !tty.trim().is_empty() && pid.parse::<u32>().is_ok() => {
+                    Ok(Request::Attach {
+                        tty: tty.trim().to_string(),
+                        pid: pid.parse().unwrap_or_default(),
+                    })
+                }
+                _ => Err("usage: ATTACH <tty> <pid>".to_string()),
+            },
+            "DETACH" if !rest.is_empty() => Ok(Request::Detach(rest.to_string())),
+            "DETACH" => Err("usage: DETACH <tty>".to_string()),
+            _ => Err(format!("unknown command: {}", command)),
+        }
+    }
+
+    /// 소켓으로 보낼 한 줄짜리 요청으로 변환합니다.
+    pub fn to_line(&self) -> String {
+        match self {
+            Request::Ping => "PING".to_string(),
+            Request::List => "LIST".to_string(),
+            Request::Add { key, due, message } => format!("ADD {} {} {}", key, due, message),
+            Request::Cancel(key) => format!("CANCEL {}", key),
+            Request::Register(tty) => format!("REGISTER {}", tty),
+            Request::Attach { tty, pid } => format!("ATTACH {} {}", tty, pid),
+            Request::Detach(tty) => format!("DETACH {}", tty),
+        }
+    }
+}
+
+/// 데몬이 들고 있는 타이머와 알림을 받을 터미널 목록입니다.
+#[derive(Debug, Default)]
+pub struct DaemonState {
+    /// 키 -> (울릴 유닉스 시각, 메시지)
+    pub timers: BTreeMap<String, (i64, String)>,
+    /// 알림을 받을 터미널 장치 경로 (예: `/dev/pts/3`)
+    pub ttys: Vec<String>,
+    /// TUI가 떠 있는 터미널 -> 앱 프로세스 id.
+    /// 화면을 그리는 중에 벨/이스케이프 시퀀스가 끼어들지 않도록 건너뛰고, 앱이 직접 울립니다.
+    pub attached: BTreeMap<String, u32>,
+}
+
+impl DaemonState {
+    /// 요청을 처리하고 응답 줄들을 반환합니다.
+    pub fn handle(&mut self, request: Request) -> Vec<String> {
+        match request {
+            Request::Ping => vec!["PONG".to_string()],
+            Request::Add { key, due, message } => {
+                self.timers.insert(key, (due, message));
+                vec!["OK".to_string()]
+            }
+            Request::Cancel(key) => match self.timers.remove(&key) {
+                Some(_) => vec!["OK".to_string()],
+                None => vec![format!("ERR unknown timer {}", key)],
+            },
+            Request::List => {
+                let mut lines: Vec<String> = self
+                    .timers
+                    .iter()
+                    .map(|(key, (due, message))| format!("TIMER {} {} {}", key, due, message))
+                    .collect();
+                lines.push("OK".to_string());
+                lines
+            }
+            Request::Register(tty) => {
+                if !self.ttys.contains(&tty) {
+                    self.ttys.push(tty);
+                }
+                vec!["OK".to_string()]
+            }
+            Request::Attach { tty, pid } => {
+                self.attached.insert(tty.clone(), pid);
+                self.handle(Request::Register(tty))
+            }
+            Request::Detach(tty) => {
+                self.attached.remove(&tty);
+                vec!["OK".to_string()]
+            }
+        }
+    }
+
+    /// 알림을 보낼 터미널 목록입니다. TUI가 떠 있는 터미널은 빼고,
+    /// 앱이 비정상 종료되어 `alive`가 아니라고 하는 연결은 정리합니다.
+    pub fn delivery_ttys(&mut self, alive: impl Fn(u32) -> bool) -> Vec<String> {
+        self.attached.retain(|_, pid| alive(*pid));
+        self.ttys
+            .iter()
+            .filter(|tty| !self.attached.contains_key(*tty))
+            .cloned()
+            .collect()
+    }
+
+    /// 울릴 시각(`now` 이하)이 된 타이머를 꺼내 메시지를 반환합니다.
+    pub fn take_due(&mut self, now: i64) -> Vec<String> {
+        let due: Vec<String> = self
+            .timers
+            .iter()
+            .filter(|(_, (at, _))| *at <= now)
+            .map(|(key, _)| key.clone())
+            .collect();
+        due.iter()
+            .filter_map(|key| self.timers.remove(key))
+            .map(|(_, message)| message)
+            .collect()
+    }
+}
+
+/// 오늘 로그의 `remind:` 알림 목록입니다.
+/// 파일이 바뀌었을 때(날짜 또는 수정 시각이 다를 때)만 다시 읽습니다.
+#[derive(Default)]
+pub struct TodayReminders {
+    source: Option<(NaiveDate, Option<SystemTime>)>,
+    reminders: Vec<Reminder>,
+}
+
+impl TodayReminders {
+    /// `date` 날짜 로그가 바뀌었으면 다시 읽고, 알림 목록을 반환합니다.
+    pub fn refresh(&mut self, log_path: &str, date: NaiveDate) -> &[Reminder] {
+        let source = Some((date, crate::storage::modified_on(log_path, date)));
+        if self.source != source {
+            let entries = crate::storage::read_entries_on(log_path, date).unwrap_or_default();
+            self.reminders = crate::storage::collect_reminders(&entries);
+            self.source = source;
+        }
+        &self.reminders
+    }
+}
+
+/// 데몬에 요청을 보내고 응답 줄들을 받습니다.
+#[cfg(unix)]
+pub fn request(socket_path: &str, request: &Request) -> io::Result<Vec<String>> {
+    use std::io::{BufRead, BufReader, Write};
+    use std::os::unix::net::UnixStream;
+
+    let mut stream = UnixStream::connect(socket_path)?;
+    stream.set_read_timeout(Some(REQUEST_TIMEOUT))?;
+    writeln!(stream, "{}", request.to_line())?;
+    BufReader::new(stream).lines().collect()
+}
+
+#[cfg(not(unix))]
+pub fn request(_socket_path: &str, _request: &Request) -> io::Result<Vec<String>> {
+    Err(io::Error::new(
+        io::ErrorKind::Unsupported,
+        "the daemon is only supported on Unix",
+    ))
+}
+
+/// 데몬을 실행합니다. 소켓 요청을 처리하면서 타이머와 오늘 로그의 `remind:` 알림을 울립니다.
+#[cfg(unix)]
+pub fn run(config: &Config) -> io::Result<()> {
+    use std::collections::HashSet;
+    use std::io::{BufRead, BufReader, Write};
+    use std::os::unix::net::UnixListener;
+
+    let socket_path = &config.daemon.socket_path;
+    // 이전 실행이 남긴 소켓 파일 정리 (다른 데몬이 살아 있으면 그대로 둠)
+    if request(socket_path, &Request::Ping).is_ok() {
+        return Err(io::Error::new(
+            io::ErrorKind::AddrInUse,
+            format!("a daemon is already listening on {}", socket_path),
+        ));
+    }
+    let _ = std::fs::remove_file(socket_path);
+
+    let listener = UnixListener::bind(socket_path)?;
+    listener.set_nonblocking(true)?;
+    println!("sonomemo daemon listening on {}", socket_path);
+
+    let mut state = DaemonState::default();
+    let started = chrono::Local::now().naive_local();
+    let mut fired_reminders: HashSet<(String, usize)> = HashSet::new();
+    let mut today_reminders = TodayReminders::default();
+
+    loop {
+        match listener.accept() {
+            Ok((stream, _)) => {
+                // 줄바꿈을 보내지 않는 클라이언트가 루프를 멈추지 않도록 읽기 제한을 둠
+                if let Err(e) = stream
+                    .set_nonblocking(false)
+                    .and_then(|_| stream.set_read_timeout(Some(REQUEST_TIMEOUT)))
+                {
+                    eprintln!("could not configure connection: {}", e);
+                    continue;
+                }
+                let mut line = String::new();
+                let _ = BufReader::new(&stream).read_line(&mut line);
+                let response = match Request::parse(&line) {
+                    Ok(request) => state.handle(request),
+                    Err(e) => vec![format!("ERR {}", e)],
+                };
+                let mut stream = stream;
+                for line in response {
+                    let _ = writeln!(stream, "{}", line);
+                }
+                continue; // 밀린 요청을 먼저 처리
+            }
+            Err(e) if e.kind() == io::ErrorKind::WouldBlock => {}
+            Err(e) => eprintln!("accept failed: {}", e),
+        }
+
+        for message in state.take_due(chrono::Local::now().timestamp()) {
+            deliver(config, &mut state, &message);
+        }
+
+        // 앱이 꺼져 있어도 할 일 알림(remind:)이 울리도록 오늘 로그를 확인
+        let now = chrono::Local::now().naive_local();
+        for reminder in today_reminders.refresh(&config.data.log_path, now.date()) {
+            let key = (reminder.entry.file_path.clone(), reminder.entry.line_number);
+            if reminder.due <= now && reminder.due >= started && fired_reminders.insert(key) {
+                deliver(config, &mut state, &format!("🔔 {}", reminder.text));
+            }
+        }
+
+        std::thread::sleep(Duration::from_millis(500));
+    }
+}
+
+#[cfg(not(unix))]
+pub fn run(_config: &Config) -> io::Result<()> {
+    Err(io::Error::new(
+        io::ErrorKind::Unsupported,
+        "the daemon is only supported on Unix",
+    ))
+}
+
+/// 등록된 터미널들(TUI가 떠 있는 곳 제외)에 벨/데스크톱 알림을 보내고, 설정된 명령이 있으면 실행합니다.
+/// 더 이상 열 수 없는 터미널은 목록에서 지웁니다.
+#[cfg(unix)]
+fn deliver(config: &Config, state: &mut DaemonState, message: &str) {
+    use std::io::Write;
+
+    let mut sequence = "\x07".repeat(config.alert.bell_count);
+    if let Some(osc) =
+        crate::notify::desktop_sequence(&config.alert.desktop_notification, "sonomemo", message)
+    {
+        sequence.push_str(&osc);
+    }
+    for tty in state.delivery_ttys(process_alive) {
+        let written = std::fs::OpenOptions::new()
+            .write(true)
+            .open(&tty)
+            .and_then(|mut file| file.write_all(sequence.as_bytes()));
+        if written.is_err() {
+            state.ttys.retain(|t| *t != tty);
+        }
+    }
+
+    if !config.daemon.command.is_empty() {
+        let mut command = std::process::Command::new("sh");
+        command
+            .arg("-c")
+            .arg(&config.daemon.command)
+            .env("SONOMEMO_MESSAGE", message);
+        std::thread::spawn(move || {
+            let _ = command.status();
+        });
+    }
+}
+
+/// `kill -0`으로 프로세스가 살아 있는지 확인합니다.
+#[cfg(unix)]
+fn process_alive(pid: u32) -> bool {
+    std::process::Command::new("kill")
+        .args(["-0", &pid.to_string()])
+        .stderr(std::process::Stdio::null())
+        .status()
+        .is_ok_and(|status| status.success())
+}
+
+/// `sonomemo timer ...` 명령을 처리합니다.
+///
+/// - `sonomemo timer add <기간> [메시지]`: 예) `sonomemo timer add 40m 빨래`
+/// - `sonomemo timer list`
+/// - `sonomemo timer cancel <키>`
+/// - `sonomemo timer register [tty]`: 지금 터미널(또는 지정한 tty)로 알림 받기
+/// - `sonomemo timer ping`
+pub fn run_cli(config: &Config, args: &[String]) -> Result<(), Box<dyn Error>> {
+    let socket_path = &config.daemon.socket_path;
+    let req = match args.first().map(String::as_str) {
+        Some("add") => {
+            let minutes = args
+                .get(1)
+                .and_then(|d| crate::ui::parser::parse_duration(d))
+                .ok_or("usage: sonomemo timer add <duration> [message]")?;
+            let now = chrono::Local::now();
+            Request::Add {
+                key: format!("cli-{}", now.timestamp_millis()),
+                due: (now + chrono::Duration::minutes(minutes)).timestamp(),
+                message: match args[2..].join(" ") {
+                    message if message.is_empty() => "⏲ Timer done!".to_string(),
+                    message => message,
+                },
+            }
+        }
+        Some("list") => Request::List,
+        Some("cancel") => Request::Cancel(
+            args.get(1)
+                .cloned()
+                .ok_or("usage: sonomemo timer cancel <key>")?,
+        ),
+        Some("register") => Request::Register(match args.get(1) {
+            Some(tty) => tty.clone(),
+            None => current_tty().ok_or("could not detect the current tty")?,
+        }),
+        Some("ping") => Request::Ping,
+        _ => return Err("usage: sonomemo timer <add|list|cancel|register|ping>".into()),
+    };
+
+    let response = request(socket_path, &req)
+        .map_err(|e| format!("could not reach the daemon at {}: {}", socket_path, e))?;
+    for line in response {
+        println!("{}", line);
+    }
+    Ok(())
+}
+
+/// `tty` 명령으로 현재 터미널 장치 경로를 알아냅니다.
+pub fn current_tty() -> Option<String> {
+    let output = std::process::Command::new("tty")
+        .stdin(std::process::Stdio::inherit())
+        .output()
+        .ok()?;
+    let tty = String::from_utf8(output.stdout).ok()?.trim().to_string();
+    (output.status.success() && tty.starts_with('/')).then_some(tty)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_request_round_trip() {
+        let requests = vec![
+            Request::Ping,
+            Request::List,
+            Request::Add {
+                key: "pomodoro".to_string(),
+                due: 1_700_000_000,
+                message: "🍅 Work finished".to_string(),
+            },
+            Request::Cancel("cli-1".to_string()),
+            Request::Register("/dev/pts/3".to_string()),
+            Request::Attach {
+                tty: "/dev/pts/3".to_string(),
+                pid: 4242,
+            },
+            Request::Detach("/dev/pts/3".to_string()),
+        ];
+        for request in requests {
+            assert_eq!(Request::parse(&request.to_line()), Ok(request));
+        }
+
+        assert!(Request::parse("ADD pomodoro soon hi").is_err());
+        assert!(Request::parse("CANCEL").is_err());
+        assert!(Request::parse("ATTACH /dev/pts/3").is_err());
+        assert!(Request::parse("HELLO").is_err());
+    }
+
+    #[test]
+    fn test_daemon_state() {
+        let mut state = DaemonState::default();
+        let add = |key: &str, due| Request::Add {
+            key: key.to_string(),
+            due,
+            message: format!("{} done", key),
+        };
+
+        assert_eq!(state.handle(Request::Ping), vec!["PONG"]);
+        assert_eq!(state.handle(add("laundry", 100)), vec!["OK"]);
+        assert_eq!(state.handle(add("standup", 200)), vec!["OK"]);
+        // 같은 키로 다시 추가하면 교체
+        assert_eq!(state.handle(add("standup", 300)), vec!["OK"]);
+        assert_eq!(
+            state.handle(Request::List),
+            vec![
+                "TIMER laundry 100 laundry done",
+                "TIMER standup 300 standup done",
+                "OK"
+            ]
+        );
+
+        assert_eq!(state.take_due(150), vec!["laundry done"]);
+        assert!(state.take_due(150).is_empty());
+
+        assert_eq!(
+            state.handle(Request::Cancel("standup".to_string())),
+            vec!["OK"]
+        );
+        assert_eq!(
+            state.handle(Request::Cancel("standup".to_string())),
+            vec!["ERR unknown timer standup"]
+        );
+        assert!(state.take_due(1000).is_empty());
+
+        state.handle(Request::Register("/dev/pts/3".to_string()));
+        state.handle(Request::Register("/dev/pts/3".to_string()));
+        assert_eq!(state.ttys, vec!["/dev/pts/3"]);
+    }
+
+    #[test]
+    fn test_attached_tty_is_skipped() {
+        let mut state = DaemonState::default();
+        let attach = |pid| Request::Attach {
+            tty: "/dev/pts/4".to_string(),
+            pid,
+        };
+        state.handle(Request::Register("/dev/pts/3".to_string()));
+        assert_eq!(state.handle(attach(100)), vec!["OK"]);
+        assert_eq!(state.ttys, vec!["/dev/pts/3", "/dev/pts/4"]);
+
+        // TUI가 떠 있는 터미널에는 보내지 않음
+        assert_eq!(state.delivery_ttys(|_| true), vec!["/dev/pts/3"]);
+
+        // TUI를 닫으면 그 터미널로도 보냄
+        assert_eq!(
+            state.handle(Request::Detach("/dev/pts/4".to_string())),
+            vec!["OK"]
+        );
+        assert_eq!(
+            state.delivery_ttys(|_| true),
+            vec!["/dev/pts/3", "/dev/pts/4"]
+        );
+
+        // DETACH 없이 앱이 죽었으면 연결을 정리하고 다시 보냄
+        state.handle(attach(200));
+        assert_eq!(
+            state.delivery_ttys(|pid| pid != 200),
+            vec!["/dev/pts/3", "/dev/pts/4"]
+        );
+        assert!(state.attached.is_empty());
+    }
+
+    #[test]
+    fn test_today_reminders_reread_on_change() {
+        let dir = std::env::temp_dir().join(format!("sonomemo-daemon-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        let log_path = dir.to_str().unwrap();
+        let date = NaiveDate::from_ymd_opt(2025, 1, 10).unwrap();
+        let file = dir.join("2025-01-10.md");
+        let set_modified = |secs: u64| {
+            std::fs::File::options()
+                .write(true)
+                .open(&file)
+                .unwrap()
+                .set_modified(SystemTime::UNIX_EPOCH + Duration::from_secs(secs))
+                .unwrap();
+        };
+
+        let mut reminders = TodayReminders::default();
+        assert!(reminders.refresh(log_path, date).is_empty());
+
+        std::fs::write(&file, "[09:00:00] - [ ] 회의 remind:10:00\n").unwrap();
+        set_modified(1_000);
+        assert_eq!(reminders.refresh(log_path, date).len(), 1);
+
+        // 수정 시각이 그대로면 다시 읽지 않음
+        std::fs::write(
+            &file,
+            "[09:00:00] - [ ] 회의 remind:10:00\n[09:10:00] - [ ] 전화 remind:11:00\n",
+        )
+        .unwrap();
+        set_modified(1_000);
+        assert_eq!(reminders.refresh(log_path, date).len(), 1);
+
+        set_modified(2_000);
+        let texts: Vec<&str> = reminders
+            .refresh(log_path, date)
+            .iter()
+            .map(|r| r.text.as_str())
+            .collect();
+        assert_eq!(texts, vec!["회의", "전화"]);
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
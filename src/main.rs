@@ -14,16 +14,26 @@ use std::{error::Error, io};
 
 mod app;
 mod config;
+mod daemon;
 mod models;
+mod notify;
 mod storage;
 mod ui;
 
 use crate::config::key_match;
 use app::App;
 use chrono::{Duration, Local};
-use models::{InputMode, Mood};
+use models::{ActivityMetric, CheckinStep, FocusPane, InputMode, PomodoroPhase};
 
 fn main() -> Result<(), Box<dyn Error>> {
+    // 하위 명령: `sonomemo daemon`, `sonomemo timer ...`
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    match args.first().map(String::as_str) {
+        Some("daemon") => return Ok(daemon::run(&config::Config::load())?),
+        Some("timer") => return daemon::run_cli(&config::Config::load(), &args[1..]),
+        _ => {}
+    }
+
     // 앱 초기화 및 설정 로드
     let mut app = App::new();
 
@@ -48,6 +58,7 @@ fn main() -> Result<(), Box<dyn Error>> {
 
     // 앱 실행
     let res = run_app(&mut terminal, &mut app);
+    app.detach_daemon();
 
     // 터미널 복구
     disable_raw_mode()?;
@@ -76,8 +87,8 @@ fn run_app<B: Backend>(terminal: &mut Terminal<B>, app: &mut App) -> io::Result<
 
         terminal.draw(|f| ui::ui(f, app))?;
 
-        // 알림 표시 중일 때는 입력을 아예 받지 않음 (강제 휴식/주목)
-        if app.pomodoro_alert_expiry.is_some() {
+        // 알림 직후 최소 시간 동안은 입력을 아예 받지 않음 (강제 휴식/주목)
+        if app.siren_input_blocked() {
             if event::poll(std::time::Duration::from_millis(100))? {
                 let _ = event::read()?; // 이벤트 소모
             }
@@ -98,6 +109,7 @@ fn run_app<B: Backend>(terminal: &mut Terminal<B>, app: &mut App) -> io::Result<
             if let Event::Key(key) = event
                 && key.kind == KeyEventKind::Press
             {
+                app.record_activity();
                 handle_key_input(app, key);
             }
         }
@@ -112,14 +124,60 @@ fn check_timers(app: &mut App) {
     if let Some(end_time) = app.pomodoro_end
         && Local::now() >= end_time
     {
-        app.pomodoro_end = None; // 타이머 종료
-        app.pomodoro_alert_expiry = Some(Local::now() + Duration::seconds(5));
+        let finished = app.pomodoro_phase;
+        app.complete_pomodoro(); // 타이머 종료 (완료 기록)
+
+        let alert = &app.config.alert;
+        app.show_siren_popup = true;
+        app.pomodoro_alert_expiry =
+            Some(Local::now() + Duration::seconds(alert.block_seconds.max(0)));
+        notify::ring_bell(alert.bell_count);
+        notify::desktop_notify(
+            &alert.desktop_notification,
+            "sonomemo",
+            &format!("{} {} finished", finished.icon(), finished.label()),
+        );
     }
 
-    if let Some(expiry) = app.pomodoro_alert_expiry
-        && Local::now() >= expiry
-    {
-        app.pomodoro_alert_expiry = None; // 알림 종료
+    // 할 일 시각 알림 (remind:)
+    if app.fire_due_reminder() {
+        if app.config.reminder.bell {
+            notify::ring_bell(1);
+        }
+        if let Some(reminder) = &app.active_reminder {
+            notify::desktop_notify(
+                &app.config.alert.desktop_notification,
+                "sonomemo",
+                &format!("🔔 {}", reminder.text),
+            );
+        }
+    }
+
+    // 이름 붙은 카운트다운 타이머
+    for timer in app.take_finished_timers() {
+        notify::ring_bell(app.config.alert.bell_count);
+        notify::desktop_notify(
+            &app.config.alert.desktop_notification,
+            "sonomemo",
+            &format!("⏲ {} done!", timer.name),
+        );
+    }
+
+    // 시간 감각 알림 (주기 알림 / 과몰입 경고)
+    if let Some(message) = app.check_nudges() {
+        if app.config.nudge.bell {
+            notify::ring_bell(1);
+        }
+        notify::desktop_notify(&app.config.alert.desktop_notification, "sonomemo", &message);
+    }
+
+    // 예약 체크인 (입력 중에는 방해하지 않고 Navigate 모드로 돌아온 뒤 물어봄)
+    if app.input_mode == InputMode::Navigate && app.check_scheduled_checkin() {
+        notify::desktop_notify(
+            &app.config.alert.desktop_notification,
+            "sonomemo",
+            "How are you doing? Time to check in.",
+        );
     }
 
     // Notification expiry check
@@ -143,6 +201,21 @@ fn handle_key_input(app: &mut App, key: event::KeyEvent) {
 }
 
 fn handle_popup_events(app: &mut App, key: event::KeyEvent) -> bool {
+    if app.show_siren_popup {
+        // 최소 차단 시간이 지난 뒤에는 아무 키나 눌러 닫기
+        app.show_siren_popup = false;
+        app.pomodoro_alert_expiry = None;
+        return true;
+    }
+    if app.celebration.is_some() {
+        // 아무 키나 누르면 닫기
+        app.celebration = None;
+        return true;
+    }
+    if app.active_reminder.is_some() {
+        handle_reminder_popup(app, key);
+        return true;
+    }
     if app.show_mood_popup {
         handle_mood_popup(app, key);
         return true;
@@ -156,14 +229,52 @@ fn handle_popup_events(app: &mut App, key: event::KeyEvent) -> bool {
         return true;
     }
     if app.show_activity_popup {
+        handle_activity_popup(app, key);
+        return true;
+    }
+    if app.show_pomodoro_stats_popup {
         // 아무 키나 누르면 닫기
-        app.show_activity_popup = false;
+        app.show_pomodoro_stats_popup = false;
+        return true;
+    }
+    if app.show_hour_popup {
+        handle_hour_popup(app, key);
+        return true;
+    }
+    if app.show_mood_trend_popup {
+        // 아무 키나 누르면 닫기
+        app.show_mood_trend_popup = false;
         return true;
     }
     if app.show_pomodoro_popup {
         handle_pomodoro_popup(app, key);
         return true;
     }
+    if app.pomodoro_next.is_some() {
+        handle_pomodoro_next_popup(app, key);
+        return true;
+    }
+    if app.show_interruption_popup {
+        handle_interruption_popup(app, key);
+        return true;
+    }
+    if app.show_parking_lot_popup {
+        handle_parking_lot_popup(app, key);
+        return true;
+    }
+    if app.show_timers_popup {
+        handle_timers_popup(app, key);
+        return true;
+    }
+    if app.show_pomodoro_cancel_popup {
+        if key_match(&key, &app.config.keybindings.popup.confirm) {
+            app.stop_pomodoro();
+            app.show_pomodoro_cancel_popup = false;
+        } else if key_match(&key, &app.config.keybindings.popup.cancel) {
+            app.show_pomodoro_cancel_popup = false;
+        }
+        return true;
+    }
     if app.show_path_popup {
         handle_path_popup(app, key);
         return true;
@@ -172,11 +283,16 @@ fn handle_popup_events(app: &mut App, key: event::KeyEvent) -> bool {
 }
 
 fn handle_mood_popup(app: &mut App, key: event::KeyEvent) {
+    if app.checkin_step != CheckinStep::Mood {
+        handle_checkin_rating(app, key);
+        return;
+    }
+    let len = app.config.moods.len().max(1);
     if key_match(&key, &app.config.keybindings.popup.up) {
         let i = match app.mood_list_state.selected() {
             Some(i) => {
                 if i == 0 {
-                    Mood::all().len() - 1
+                    len - 1
                 } else {
                     i - 1
                 }
@@ -187,7 +303,7 @@ fn handle_mood_popup(app: &mut App, key: event::KeyEvent) {
     } else if key_match(&key, &app.config.keybindings.popup.down) {
         let i = match app.mood_list_state.selected() {
             Some(i) => {
-                if i >= Mood::all().len() - 1 {
+                if i >= len - 1 {
                     0
                 } else {
                     i + 1
@@ -197,19 +313,125 @@ fn handle_mood_popup(app: &mut App, key: event::KeyEvent) {
         };
         app.mood_list_state.select(Some(i));
     } else if key_match(&key, &app.config.keybindings.popup.confirm) {
-        if let Some(i) = app.mood_list_state.selected() {
-            let mood = Mood::all()[i];
-            let _ = storage::append_entry(
-                &app.config.data.log_path,
-                &format!("Mood: {}", mood.as_str()),
-            );
-            app.update_logs();
+        if app.mood_list_state.selected().is_some() {
+            app.checkin_step = CheckinStep::Energy;
         }
-        check_carryover(app);
-        app.show_mood_popup = false;
     } else if key_match(&key, &app.config.keybindings.popup.cancel) {
         app.show_mood_popup = false;
-        app.transition_to(InputMode::Editing);
+        if app.checkin_startup {
+            app.checkin_startup = false;
+            app.transition_to(InputMode::Editing);
+        }
+    }
+}
+
+/// 에너지/집중도 단계: 점수 키(기본 1-5)로 바로 고르거나 위/아래로 조정 후 확인합니다.
+/// 취소하면 남은 점수를 건너뛰고 지금까지의 체크인만 기록합니다.
+fn handle_checkin_rating(app: &mut App, key: event::KeyEvent) {
+    let rating = app
+        .config
+        .keybindings
+        .popup
+        .ratings
+        .iter()
+        .take(5)
+        .position(|binding| key_match(&key, std::slice::from_ref(binding)));
+    let value = match app.checkin_step {
+        CheckinStep::Energy => &mut app.checkin_energy,
+        _ => &mut app.checkin_focus,
+    };
+    let mut advance = false;
+    if let Some(i) = rating {
+        *value = i as u8 + 1;
+        advance = true;
+    } else if key_match(&key, &app.config.keybindings.popup.up) {
+        *value = (*value + 1).min(5);
+    } else if key_match(&key, &app.config.keybindings.popup.down) {
+        *value = value.saturating_sub(1).max(1);
+    } else if key_match(&key, &app.config.keybindings.popup.confirm) {
+        advance = true;
+    } else if key_match(&key, &app.config.keybindings.popup.cancel) {
+        let energy = (app.checkin_step == CheckinStep::Focus).then_some(app.checkin_energy);
+        finish_checkin(app, energy, None);
+        return;
+    }
+
+    if advance {
+        if app.checkin_step == CheckinStep::Energy {
+            app.checkin_step = CheckinStep::Focus;
+        } else {
+            let (energy, focus) = (app.checkin_energy, app.checkin_focus);
+            finish_checkin(app, Some(energy), Some(focus));
+        }
+    }
+}
+
+fn finish_checkin(app: &mut App, energy: Option<u8>, focus: Option<u8>) {
+    app.finish_checkin(energy, focus);
+    if app.checkin_startup {
+        app.checkin_startup = false;
+        check_carryover(app);
+    }
+}
+
+/// 활동 히트맵: 1년씩 넘기기, 한 주/하루씩 커서 이동, 지표 전환, 그날 로그 열기 (`[keybindings.activity]`)
+fn handle_activity_popup(app: &mut App, key: event::KeyEvent) {
+    let bindings = &app.config.keybindings.activity;
+    let metric = bindings
+        .metrics
+        .iter()
+        .take(ActivityMetric::ALL.len())
+        .position(|binding| key_match(&key, std::slice::from_ref(binding)));
+    if let Some(i) = metric {
+        app.activity_metric = ActivityMetric::ALL[i];
+    } else if key_match(&key, &bindings.prev_year) {
+        app.page_activity_year(-1);
+    } else if key_match(&key, &bindings.next_year) {
+        app.page_activity_year(1);
+    } else if key_match(&key, &bindings.prev_week) {
+        app.move_activity_cursor(-7);
+    } else if key_match(&key, &bindings.next_week) {
+        app.move_activity_cursor(7);
+    } else if key_match(&key, &bindings.prev_day) {
+        app.move_activity_cursor(-1);
+    } else if key_match(&key, &bindings.next_day) {
+        app.move_activity_cursor(1);
+    } else if key_match(&key, &bindings.open_day) {
+        // 선택한 날의 로그를 메인 화면에 불러옴
+        app.show_activity_popup = false;
+        app.view_day(app.activity_cursor);
+    } else if key_match(&key, &bindings.next_metric) {
+        app.activity_metric = app.activity_metric.cycle(true);
+    } else if key_match(&key, &bindings.prev_metric) {
+        app.activity_metric = app.activity_metric.cycle(false);
+    } else if key_match(&key, &bindings.close) {
+        app.show_activity_popup = false;
+    }
+}
+
+/// 시간대 분포: 지표 전환, 글자를 입력해 태그 필터를 고치고 적용 (`[keybindings.hour]`)
+fn handle_hour_popup(app: &mut App, key: event::KeyEvent) {
+    let bindings = &app.config.keybindings.hour;
+    if key_match(&key, &bindings.close) {
+        app.show_hour_popup = false;
+    } else if key_match(&key, &bindings.next_metric) {
+        app.hour_metric = app.hour_metric.cycle(true);
+    } else if key_match(&key, &bindings.prev_metric) {
+        app.hour_metric = app.hour_metric.cycle(false);
+    } else if key_match(&key, &bindings.apply) {
+        app.refresh_hour_stats();
+    } else if key_match(&key, &bindings.erase) {
+        app.hour_tag_filter.pop();
+    } else if let KeyCode::Char(c) = key.code {
+        app.hour_tag_filter.push(c);
+    }
+}
+
+fn handle_reminder_popup(app: &mut App, key: event::KeyEvent) {
+    if key_match(&key, &app.config.keybindings.reminder.snooze) {
+        app.snooze_reminder();
+    } else if key_match(&key, &app.config.keybindings.reminder.dismiss) {
+        app.dismiss_reminder();
     }
 }
 
@@ -252,39 +474,21 @@ fn handle_todo_popup(app: &mut App, key: event::KeyEvent) {
 
 fn handle_tag_popup(app: &mut App, key: event::KeyEvent) {
     if key_match(&key, &app.config.keybindings.popup.up) {
-        let i = match app.tag_list_state.selected() {
-            Some(i) => {
-                if i == 0 {
-                    0
-                } else {
-                    i - 1
-                }
-            }
-            None => 0,
-        };
-        app.tag_list_state.select(Some(i));
+        app.move_tag_selection(-1);
     } else if key_match(&key, &app.config.keybindings.popup.down) {
-        let i = match app.tag_list_state.selected() {
-            Some(i) => {
-                if i >= app.tags.len() - 1 {
-                    app.tags.len() - 1
-                } else {
-                    i + 1
-                }
-            }
-            None => 0,
-        };
-        app.tag_list_state.select(Some(i));
+        app.move_tag_selection(1);
+    } else if key_match(&key, &app.config.keybindings.tags.collapse) {
+        app.collapse_selected_tag();
+    } else if key_match(&key, &app.config.keybindings.tags.expand) {
+        app.expand_selected_tag();
     } else if key_match(&key, &app.config.keybindings.popup.confirm) {
-        if let Some(i) = app.tag_list_state.selected()
-            && i < app.tags.len()
+        // 상위 태그를 고르면 하위 태그가 붙은 로그까지 모두 찾습니다
+        if let Some(query) = app.selected_tag().map(|node| node.path.clone())
+            && let Ok(results) = storage::search_entries(&app.config.data.log_path, &query)
         {
-            let query = app.tags[i].0.clone();
-            if let Ok(results) = storage::search_entries(&app.config.data.log_path, &query) {
-                app.logs = results;
-                app.is_search_result = true;
-                app.logs_state.select(Some(0));
-            }
+            app.logs = results;
+            app.is_search_result = true;
+            app.logs_state.select(Some(0));
         }
         app.show_tag_popup = false;
         app.transition_to(InputMode::Navigate);
@@ -295,42 +499,196 @@ fn handle_tag_popup(app: &mut App, key: event::KeyEvent) {
 }
 
 fn handle_pomodoro_popup(app: &mut App, key: event::KeyEvent) {
+    // 입력 형식: "25 #project" (분 + 선택적 태그)
+    // 태그를 입력하기 시작하면(#) 문자 키는 확인/취소 바인딩(y/n 등)보다 입력이 우선
+    match key.code {
+        KeyCode::Char(c)
+            if c.is_ascii_digit() || c == '#' || c == ' ' || app.pomodoro_input.contains('#') =>
+        {
+            app.pomodoro_input.push(c);
+            return;
+        }
+        KeyCode::Backspace => {
+            app.pomodoro_input.pop();
+            return;
+        }
+        _ => {}
+    }
+
     if key_match(&key, &app.config.keybindings.popup.confirm) {
-        let mins: i64 = app.pomodoro_input.parse().unwrap_or(25);
+        let mut words = app.pomodoro_input.split_whitespace();
+        let mins: i64 = words
+            .next()
+            .and_then(|m| m.parse().ok())
+            .unwrap_or(app.config.pomodoro.work_minutes);
+        let tags: Vec<String> = words
+            .filter(|w| w.starts_with('#') && w.len() > 1)
+            .map(|w| w.to_string())
+            .collect();
         if mins > 0 {
-            app.pomodoro_end = Some(Local::now() + Duration::minutes(mins));
+            app.start_pomodoro(PomodoroPhase::Work, mins, tags);
         }
         app.show_pomodoro_popup = false;
         app.pomodoro_input.clear();
     } else if key_match(&key, &app.config.keybindings.popup.cancel) {
         app.show_pomodoro_popup = false;
         app.pomodoro_input.clear();
+    }
+}
+
+/// 오늘의 할 일 패널에 포커스가 있을 때의 키 입력을 처리합니다.
+/// 처리한 키라면 `true`를 반환하고, 나머지는 일반 Navigate 키로 넘깁니다.
+fn handle_tasks_pane(app: &mut App, key: event::KeyEvent) -> bool {
+    // shift+up/down이 단순 up/down보다 먼저 매칭되어야 함
+    if key_match(&key, &app.config.keybindings.navigate.move_task_up) {
+        if !blocked_by_read_only(app) {
+            app.move_selected_task(-1);
+        }
+    } else if key_match(&key, &app.config.keybindings.navigate.move_task_down) {
+        if !blocked_by_read_only(app) {
+            app.move_selected_task(1);
+        }
+    } else if key.code == KeyCode::Up {
+        app.task_up();
+    } else if key.code == KeyCode::Down {
+        app.task_down();
+    } else if key_match(&key, &app.config.keybindings.navigate.toggle_todo) {
+        if let Some(i) = app.selected_task_index() {
+            toggle_todo_at(app, i);
+        }
+    } else if key_match(&key, &app.config.keybindings.navigate.jump_to_log) {
+        app.jump_to_selected_task();
     } else {
-        match key.code {
-            KeyCode::Char(c) if c.is_ascii_digit() => {
-                app.pomodoro_input.push(c);
-            }
-            KeyCode::Backspace => {
-                app.pomodoro_input.pop();
-            }
-            _ => {}
+        return false;
+    }
+    true
+}
+
+/// `logs[i]` 항목의 할 일 완료 상태를 토글하고 로그를 다시 읽어옵니다.
+fn toggle_todo_at(app: &mut App, i: usize) {
+    if blocked_by_read_only(app) {
+        return;
+    }
+    if i < app.logs.len() {
+        // 작업 타이머가 돌던 할 일이면 사용 시간을 먼저 기록
+        let content = ui::parser::extract_pending_content(&app.logs[i].content);
+        if content.is_some() && app.todo_timer.as_ref().map(|(todo, _)| todo) == content.as_ref() {
+            app.stop_todo_timer();
         }
+        let entry = &app.logs[i];
+        // Just call toggle logic; let it decide if it's a todo
+        let _ = storage::toggle_todo_status(entry);
+        app.update_logs();
+        app.logs_state.select(Some(i));
+    }
+}
+
+/// 지난 날짜를 읽기 전용으로 보는 중이면 안내하고 `true`를 반환합니다.
+fn blocked_by_read_only(app: &mut App) -> bool {
+    let Some(date) = app.viewing_date.filter(|_| app.is_read_only()) else {
+        return false;
+    };
+    app.notification = Some((
+        format!(
+            "📖 {} is read-only (Esc: back to today)",
+            date.format("%Y-%m-%d")
+        ),
+        Local::now() + Duration::seconds(2),
+    ));
+    true
+}
+
+fn handle_pomodoro_next_popup(app: &mut App, key: event::KeyEvent) {
+    if key_match(&key, &app.config.keybindings.popup.confirm) {
+        if let Some(next) = app.pomodoro_next.take() {
+            app.start_next_pomodoro_phase(next);
+        }
+    } else if key_match(&key, &app.config.keybindings.popup.cancel) {
+        app.pomodoro_next = None; // 사이클 종료
+    }
+}
+
+fn handle_interruption_popup(app: &mut App, key: event::KeyEvent) {
+    let bindings = &app.config.keybindings.interruption;
+    if key_match(&key, &bindings.submit) {
+        app.record_interruption();
+    } else if key_match(&key, &bindings.cancel) {
+        app.show_interruption_popup = false;
+    } else if key_match(&key, &bindings.toggle_kind) {
+        app.interruption_external = !app.interruption_external;
+    } else if key.code == KeyCode::Backspace {
+        app.interruption_input.pop();
+    } else if let KeyCode::Char(c) = key.code {
+        app.interruption_input.push(c);
+    }
+}
+
+fn handle_timers_popup(app: &mut App, key: event::KeyEvent) {
+    // 문자 키는 모두 입력으로 사용 ("빨래 40m", "standup in 10m", "통화")
+    let len = app.timers.len();
+    let bindings = &app.config.keybindings.timers;
+    if key_match(&key, &bindings.add) {
+        app.add_timer_from_input();
+    } else if key_match(&key, &bindings.close) {
+        app.show_timers_popup = false;
+        app.timer_input.clear();
+    } else if key_match(&key, &bindings.remove) {
+        app.remove_selected_timer();
+    } else if key_match(&key, &bindings.up) {
+        if len > 0 {
+            let i = app.timers_state.selected().unwrap_or(0);
+            app.timers_state
+                .select(Some(if i == 0 { len - 1 } else { i - 1 }));
+        }
+    } else if key_match(&key, &bindings.down) {
+        if len > 0 {
+            let i = app.timers_state.selected().unwrap_or(0);
+            app.timers_state
+                .select(Some(if i + 1 >= len { 0 } else { i + 1 }));
+        }
+    } else if key.code == KeyCode::Backspace {
+        app.timer_input.pop();
+    } else if let KeyCode::Char(c) = key.code {
+        app.timer_input.push(c);
+    }
+}
+
+fn handle_parking_lot_popup(app: &mut App, key: event::KeyEvent) {
+    let len = app.parking_lot.len();
+    if key_match(&key, &app.config.keybindings.popup.up) {
+        let i = app.parking_lot_state.selected().unwrap_or(0);
+        app.parking_lot_state
+            .select(Some(if i == 0 { len.saturating_sub(1) } else { i - 1 }));
+    } else if key_match(&key, &app.config.keybindings.popup.down) {
+        let i = app.parking_lot_state.selected().unwrap_or(0);
+        app.parking_lot_state
+            .select(Some(if i + 1 >= len { 0 } else { i + 1 }));
+    } else if key_match(&key, &app.config.keybindings.popup.confirm) {
+        app.parking_lot_to_todo();
+    } else if key_match(&key, &app.config.keybindings.popup.discard) {
+        app.discard_parking_lot_item();
+    } else if key_match(&key, &app.config.keybindings.popup.cancel) {
+        app.close_parking_lot();
     }
 }
 
 fn handle_normal_mode(app: &mut App, key: event::KeyEvent) {
+    if key_match(&key, &app.config.keybindings.navigate.switch_pane) {
+        app.toggle_focus();
+        return;
+    }
+    if app.focus == FocusPane::Tasks && handle_tasks_pane(app, key) {
+        return;
+    }
+
     if key_match(&key, &app.config.keybindings.navigate.tags) {
-        if let Ok(tags) = storage::get_all_tags(&app.config.data.log_path) {
-            app.tags = tags;
-            if !app.tags.is_empty() {
-                app.tag_list_state.select(Some(0));
-                app.show_tag_popup = true;
-            }
-        }
+        app.open_tag_popup();
     } else if key_match(&key, &app.config.keybindings.navigate.quit) {
         app.quit();
     } else if key_match(&key, &app.config.keybindings.navigate.insert) {
-        app.transition_to(InputMode::Editing);
+        if !blocked_by_read_only(app) {
+            app.transition_to(InputMode::Editing);
+        }
     } else if key_match(&key, &app.config.keybindings.navigate.search) {
         app.transition_to(InputMode::Search);
     } else if key.code == KeyCode::Up {
@@ -338,31 +696,62 @@ fn handle_normal_mode(app: &mut App, key: event::KeyEvent) {
     } else if key.code == KeyCode::Down {
         app.scroll_down();
     } else if key.code == KeyCode::Esc {
-        if app.is_search_result {
+        // 검색 결과나 지난 날짜를 보던 중이면 오늘로 돌아감
+        if app.viewing_date.is_some() {
+            app.view_day(Local::now().date_naive());
+        } else if app.is_search_result {
             app.update_logs();
         }
     } else if key_match(&key, &app.config.keybindings.navigate.toggle_todo) {
-        if let Some(i) = app.logs_state.selected()
-            && i < app.logs.len()
-        {
-            let entry = &app.logs[i];
-            // Just call toggle logic; let it decide if it's a todo
-            let _ = storage::toggle_todo_status(entry);
-            app.update_logs();
-            app.logs_state.select(Some(i));
+        if let Some(i) = app.logs_state.selected() {
+            toggle_todo_at(app, i);
         }
     } else if key_match(&key, &app.config.keybindings.navigate.pomodoro) {
-        if app.pomodoro_end.is_some() {
-            app.pomodoro_end = None; // 끄기
+        if app.pomodoro_active() {
+            app.toggle_pomodoro_pause(); // 일시정지 / 재개
         } else {
             app.show_pomodoro_popup = true;
-            app.pomodoro_input = "25".to_string();
+            // 집중 중인 할 일이 있으면 그 태그를 미리 채워둠
+            let focus_tags: Vec<&str> = app
+                .current_focus
+                .iter()
+                .flat_map(|focus| focus.split_whitespace())
+                .filter(|w| w.starts_with('#') && w.len() > 1)
+                .collect();
+            app.pomodoro_input = std::iter::once(app.config.pomodoro.work_minutes.to_string())
+                .chain(focus_tags.into_iter().map(String::from))
+                .collect::<Vec<_>>()
+                .join(" ");
+        }
+    } else if key_match(&key, &app.config.keybindings.navigate.pomodoro_extend) {
+        app.extend_pomodoro();
+    } else if key_match(&key, &app.config.keybindings.navigate.interrupt) {
+        app.open_interruption_popup();
+    } else if key_match(&key, &app.config.keybindings.navigate.pomodoro_cancel) {
+        if app.pomodoro_active() {
+            app.show_pomodoro_cancel_popup = true;
         }
     } else if key_match(&key, &app.config.keybindings.navigate.graph) {
         if let Ok(data) = storage::get_activity_stats(&app.config.data.log_path) {
             app.activity_data = data;
+            app.activity_cursor = Local::now().date_naive();
+            app.todo_cycle_stats =
+                storage::get_todo_cycle_stats(&app.config.data.log_path).unwrap_or_default();
             app.show_activity_popup = true;
         }
+    } else if key_match(&key, &app.config.keybindings.navigate.pomodoro_stats) {
+        if let Ok(stats) = storage::get_pomodoro_stats(&app.config.data.log_path) {
+            app.pomodoro_stats = stats;
+            app.show_pomodoro_stats_popup = true;
+        }
+    } else if key_match(&key, &app.config.keybindings.navigate.mood_trend) {
+        if let Ok(stats) = storage::get_mood_stats(&app.config.data.log_path, &app.config.moods) {
+            app.mood_stats = stats;
+            app.show_mood_trend_popup = true;
+        }
+    } else if key_match(&key, &app.config.keybindings.navigate.hour_stats) {
+        app.refresh_hour_stats();
+        app.show_hour_popup = true;
     } else if key_match(&key, &app.config.keybindings.navigate.path) {
         // Initialize selection
         app.path_list_state.select(Some(0));
@@ -373,6 +762,21 @@ fn handle_normal_mode(app: &mut App, key: event::KeyEvent) {
         app.jump_prev_todo();
     } else if key_match(&key, &app.config.keybindings.navigate.copy) {
         app.copy_current_log();
+    } else if key_match(&key, &app.config.keybindings.navigate.focus_todo) {
+        if !blocked_by_read_only(app) {
+            app.focus_selected_todo();
+        }
+    } else if key_match(&key, &app.config.keybindings.navigate.timers) {
+        app.show_timers_popup = true;
+        if app.timers_state.selected().is_none() && !app.timers.is_empty() {
+            app.timers_state.select(Some(0));
+        }
+    } else if key_match(&key, &app.config.keybindings.navigate.todo_timer) {
+        if !blocked_by_read_only(app) {
+            app.toggle_todo_timer();
+        }
+    } else if key_match(&key, &app.config.keybindings.navigate.checkin) {
+        app.open_checkin();
     }
 }
 
@@ -403,10 +807,30 @@ fn handle_search_mode(app: &mut App, key: event::KeyEvent) {
 }
 
 fn handle_editing_mode(app: &mut App, key: event::KeyEvent) {
+    // 태그 자동완성 후보가 떠 있으면 선택/닫기/이동 키를 먼저 처리
+    if !app.tag_suggestions.is_empty() {
+        let len = app.tag_suggestions.len();
+        let bindings = &app.config.keybindings.editing;
+        if key_match(&key, &bindings.tag_accept) {
+            app.accept_tag_suggestion();
+            return;
+        } else if key_match(&key, &bindings.tag_dismiss) {
+            app.tag_suggestions.clear();
+            return;
+        } else if key_match(&key, &bindings.tag_prev) {
+            app.tag_suggestion_index = (app.tag_suggestion_index + len - 1) % len;
+            return;
+        } else if key_match(&key, &bindings.tag_next) {
+            app.tag_suggestion_index = (app.tag_suggestion_index + 1) % len;
+            return;
+        }
+    }
+
     if key_match(&key, &app.config.keybindings.editing.cancel) {
         app.transition_to(InputMode::Navigate);
     } else if key_match(&key, &app.config.keybindings.editing.newline) {
         app.textarea.insert_newline();
+        app.tag_suggestions.clear();
     } else if key_match(&key, &app.config.keybindings.editing.save)
         || (key.code == event::KeyCode::Enter
             && !key.modifiers.contains(event::KeyModifiers::SHIFT))
@@ -420,8 +844,13 @@ fn handle_editing_mode(app: &mut App, key: event::KeyEvent) {
             .join("\n           ");
 
         if !input.trim().is_empty() {
-            if let Err(e) = storage::append_entry(&app.config.data.log_path, &input) {
-                eprintln!("Error saving: {}", e);
+            // 지난 날짜를 편집 중이면 그날 파일에 추가
+            let date = app
+                .viewing_date
+                .unwrap_or_else(|| Local::now().date_naive());
+            match storage::append_entry_on(&app.config.data.log_path, date, &input) {
+                Ok(()) => app.remember_tags(&input),
+                Err(e) => eprintln!("Error saving: {}", e),
             }
             app.update_logs();
         }
@@ -431,6 +860,7 @@ fn handle_editing_mode(app: &mut App, key: event::KeyEvent) {
         app.transition_to(InputMode::Editing);
     } else {
         app.textarea.input(key);
+        app.update_tag_suggestions();
     }
 }
 
@@ -9,45 +9,6 @@ pub enum InputMode {
     Search,
 }
 
-/// 사용자의 기분 상태를 나타내는 열거형입니다.
-#[derive(Clone, Copy, PartialEq)]
-pub enum Mood {
-    /// 기분이 좋을 때 (Happy)
-    Happy,
-    /// 평범할 때 (Neutral)
-    Neutral,
-    /// 스트레스 받을 때 (Stressed)
-    Stressed,
-    /// 집중하고 있을 때 (Focused)
-    Focused,
-    /// 피곤할 때 (Tired)
-    Tired,
-}
-
-impl Mood {
-    /// 지원되는 모든 기분 상태의 리스트를 반환합니다.
-    pub fn all() -> Vec<Mood> {
-        vec![
-            Mood::Happy,
-            Mood::Neutral,
-            Mood::Stressed,
-            Mood::Focused,
-            Mood::Tired,
-        ]
-    }
-
-    /// 기분 상태에 해당하는 이모지와 텍스트 설명을 반환합니다.
-    pub fn as_str(&self) -> &'static str {
-        match self {
-            Mood::Happy => "😊 조음",
-            Mood::Neutral => "😐 걍그럼",
-            Mood::Stressed => "😫 구림",
-            Mood::Focused => "🧐 집중",
-            Mood::Tired => "😴 피곤",
-        }
-    }
-}
-
 /// 파싱된 로그 항목을 나타내는 구조체입니다.
 #[derive(Clone)]
 pub struct LogEntry {
@@ -58,3 +19,770 @@ pub struct LogEntry {
     /// 파일 내에서의 라인 번호입니다 (0-based 또는 1-based, storage 구현에 따름).
     pub line_number: usize,
 }
+
+/// 반복 할 일(Recurring todo)의 반복 주기를 나타냅니다.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Recurrence {
+    /// 매일 반복 (`every:day`)
+    Daily,
+    /// 지정한 요일마다 반복 (`every:mon,thu`)
+    Weekdays(Vec<chrono::Weekday>),
+    /// 매월 지정한 날짜마다 반복 (`every:1st`, `every:1st,15th`)
+    MonthDays(Vec<u32>),
+}
+
+impl Recurrence {
+    /// 주어진 날짜가 반복 주기에 해당하는지 확인합니다.
+    pub fn matches(&self, date: chrono::NaiveDate) -> bool {
+        use chrono::Datelike;
+        match self {
+            Recurrence::Daily => true,
+            Recurrence::Weekdays(days) => days.contains(&date.weekday()),
+            Recurrence::MonthDays(days) => days.contains(&date.day()),
+        }
+    }
+}
+
+/// 할 일의 생성부터 완료까지 걸린 시간(사이클 타임) 통계입니다.
+#[derive(Debug, Clone, Default)]
+pub struct TodoCycleStats {
+    /// 완료 시각이 기록된 할 일의 수
+    pub completed: usize,
+    /// 모든 완료 할 일의 사이클 타임 합계 (분)
+    pub total_minutes: i64,
+    /// 가장 오래 걸린 할 일의 사이클 타임 (분)
+    pub longest_minutes: i64,
+    /// 예상 시간(`~45m`)과 실제 사용 시간(`spent:`)이 모두 기록된 완료 할 일의 수
+    pub estimated: usize,
+    /// 그 할 일들의 예상 시간 합계 (분)
+    pub estimate_minutes: i64,
+    /// 그 할 일들의 실제 사용 시간 합계 (분)
+    pub spent_minutes: i64,
+    /// 실제 사용 시간이 예상의 ±25% 안에 든 할 일의 수
+    pub accurate: usize,
+}
+
+impl TodoCycleStats {
+    /// 평균 사이클 타임(분)을 반환합니다. 완료된 할 일이 없으면 `None`입니다.
+    pub fn average_minutes(&self) -> Option<i64> {
+        if self.completed == 0 {
+            None
+        } else {
+            Some(self.total_minutes / self.completed as i64)
+        }
+    }
+
+    /// 실제 사용 시간이 예상 시간의 몇 배였는지 반환합니다. (1.0이면 정확, 2.0이면 두 배 걸림)
+    pub fn estimate_ratio(&self) -> Option<f64> {
+        if self.estimated == 0 || self.estimate_minutes == 0 {
+            None
+        } else {
+            Some(self.spent_minutes as f64 / self.estimate_minutes as f64)
+        }
+    }
+}
+
+/// 로그에 남은 뽀모도로 기록으로 집계한 집중 통계입니다.
+#[derive(Debug, Clone, Default)]
+pub struct PomodoroStats {
+    /// 날짜별 (끝까지 마친 집중 세션 수, 집중 시간(분))
+    pub daily: std::collections::BTreeMap<chrono::NaiveDate, (usize, i64)>,
+    /// 태그별 집중 시간(분), 많은 순
+    pub tag_minutes: Vec<(String, i64)>,
+    /// 시작한 집중 세션 수 (중단 포함)
+    pub started: usize,
+    /// 끝까지 마친 집중 세션 수
+    pub completed: usize,
+    /// 마친 세션에 기록된 방해 횟수 합계 (내부 + 외부)
+    pub interruptions: usize,
+}
+
+impl PomodoroStats {
+    /// 시작한 집중 세션 중 끝까지 마친 비율(0.0 ~ 1.0)입니다.
+    pub fn completion_rate(&self) -> Option<f64> {
+        if self.started == 0 {
+            None
+        } else {
+            Some(self.completed as f64 / self.started as f64)
+        }
+    }
+
+    /// 마친 세션당 평균 방해 횟수입니다.
+    pub fn average_interruptions(&self) -> Option<f64> {
+        if self.completed == 0 {
+            None
+        } else {
+            Some(self.interruptions as f64 / self.completed as f64)
+        }
+    }
+}
+
+/// 활동 히트맵에 표시할 지표입니다.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum ActivityMetric {
+    /// 로그 항목 수 (여러 줄 항목은 하나, 체크인과 앱이 남긴 기록 제외)
+    #[default]
+    Entries,
+    /// 새로 적은 할 일 수 (이월된 할 일은 제외)
+    TodosCreated,
+    /// 완료한 할 일 수 (완료 시각 기준)
+    TodosCompleted,
+    /// 쓴 단어 수
+    Words,
+    /// 끝까지 마친 집중 세션의 시간(분)
+    PomodoroMinutes,
+    /// 사용한 서로 다른 태그 수
+    Tags,
+}
+
+impl ActivityMetric {
+    pub const ALL: [ActivityMetric; 6] = [
+        ActivityMetric::Entries,
+        ActivityMetric::TodosCreated,
+        ActivityMetric::TodosCompleted,
+        ActivityMetric::Words,
+        ActivityMetric::PomodoroMinutes,
+        ActivityMetric::Tags,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            ActivityMetric::Entries => "entries",
+            ActivityMetric::TodosCreated => "todos created",
+            ActivityMetric::TodosCompleted => "todos completed",
+            ActivityMetric::Words => "words",
+            ActivityMetric::PomodoroMinutes => "focus minutes",
+            ActivityMetric::Tags => "tags",
+        }
+    }
+
+    /// 다음(`forward`가 거짓이면 이전) 지표로 순환합니다.
+    pub fn cycle(&self, forward: bool) -> ActivityMetric {
+        let len = Self::ALL.len();
+        let index = Self::ALL.iter().position(|m| m == self).unwrap_or(0);
+        let next = if forward {
+            (index + 1) % len
+        } else {
+            (index + len - 1) % len
+        };
+        Self::ALL[next]
+    }
+}
+
+/// 하루 동안의 활동량입니다.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DayActivity {
+    pub entries: usize,
+    pub todos_created: usize,
+    pub todos_completed: usize,
+    pub words: usize,
+    pub pomodoro_minutes: usize,
+    pub tags: usize,
+}
+
+impl DayActivity {
+    pub fn value(&self, metric: ActivityMetric) -> usize {
+        match metric {
+            ActivityMetric::Entries => self.entries,
+            ActivityMetric::TodosCreated => self.todos_created,
+            ActivityMetric::TodosCompleted => self.todos_completed,
+            ActivityMetric::Words => self.words,
+            ActivityMetric::PomodoroMinutes => self.pomodoro_minutes,
+            ActivityMetric::Tags => self.tags,
+        }
+    }
+}
+
+/// 연속 기록 일수입니다.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Streak {
+    /// 지금 이어지고 있는 연속 일수. 오늘 아직 기록이 없어도 어제까지 이어졌다면 유지됩니다.
+    pub current: usize,
+    /// 가장 길었던 연속 일수
+    pub longest: usize,
+}
+
+impl Streak {
+    /// 조건을 만족한 날짜들(오름차순)로 연속 일수를 계산합니다.
+    pub fn from_days(
+        days: impl IntoIterator<Item = chrono::NaiveDate>,
+        today: chrono::NaiveDate,
+    ) -> Streak {
+        let mut streak = Streak::default();
+        let mut run = 0;
+        let mut last: Option<chrono::NaiveDate> = None;
+        for day in days {
+            run = match last {
+                Some(prev) if day - prev == chrono::Duration::days(1) => run + 1,
+                Some(prev) if day == prev => run,
+                _ => 1,
+            };
+            streak.longest = streak.longest.max(run);
+            last = Some(day);
+        }
+        if let Some(last) = last
+            && (last == today || last + chrono::Duration::days(1) == today)
+        {
+            streak.current = run;
+        }
+        streak
+    }
+}
+
+/// 앱을 다시 켜도 유지되어야 하는 달성 기록입니다. (`state.toml`)
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
+pub struct AppState {
+    /// 달성한 이정표 -> 달성한 날짜 (YYYY-MM-DD)
+    #[serde(default)]
+    pub milestones: std::collections::BTreeMap<String, String>,
+    /// 축하를 마친 날짜 (YYYY-MM-DD)
+    #[serde(default)]
+    pub celebrated_date: String,
+    /// 그날 이미 축하한 목표들
+    #[serde(default)]
+    pub celebrated: Vec<String>,
+}
+
+/// 시간대 분포 팝업에 표시할 지표입니다.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum HourMetric {
+    /// 로그를 남긴 시각 (체크인과 앱이 남긴 기록 제외)
+    #[default]
+    Entries,
+    /// 집중 세션을 시작한 시각
+    Focus,
+    /// 할 일을 완료한 시각 (`done:`)
+    TodosDone,
+}
+
+impl HourMetric {
+    pub fn label(&self) -> &'static str {
+        match self {
+            HourMetric::Entries => "entries",
+            HourMetric::Focus => "focus sessions",
+            HourMetric::TodosDone => "todos done",
+        }
+    }
+
+    pub fn cycle(&self, forward: bool) -> HourMetric {
+        match (self, forward) {
+            (HourMetric::Entries, true) | (HourMetric::TodosDone, false) => HourMetric::Focus,
+            (HourMetric::Focus, true) | (HourMetric::Entries, false) => HourMetric::TodosDone,
+            (HourMetric::TodosDone, true) | (HourMetric::Focus, false) => HourMetric::Entries,
+        }
+    }
+}
+
+/// 요일(월~일) × 시(0~23)별 횟수입니다.
+pub type HourGrid = [[usize; 24]; 7];
+
+/// 로그 타임스탬프로 집계한 시간대 분포입니다.
+#[derive(Debug, Clone, Default)]
+pub struct HourStats {
+    pub entries: HourGrid,
+    pub focus: HourGrid,
+    pub todos_done: HourGrid,
+}
+
+impl HourStats {
+    pub fn grid(&self, metric: HourMetric) -> &HourGrid {
+        match metric {
+            HourMetric::Entries => &self.entries,
+            HourMetric::Focus => &self.focus,
+            HourMetric::TodosDone => &self.todos_done,
+        }
+    }
+
+    /// 요일을 합친 시간대별 횟수
+    pub fn by_hour(&self, metric: HourMetric) -> [usize; 24] {
+        let mut hours = [0; 24];
+        for row in self.grid(metric) {
+            for (hour, count) in row.iter().enumerate() {
+                hours[hour] += count;
+            }
+        }
+        hours
+    }
+}
+
+/// 하루 동안의 체크인 점수와 활동량입니다.
+#[derive(Debug, Clone, Default)]
+pub struct MoodDay {
+    /// 기분 점수(`valence`)의 합과 개수
+    pub mood_sum: i64,
+    pub mood_count: usize,
+    /// 에너지(1~5)의 합과 개수
+    pub energy_sum: i64,
+    pub energy_count: usize,
+    /// 집중도(1~5)의 합과 개수
+    pub focus_sum: i64,
+    pub focus_count: usize,
+    /// 직접 남긴 기록 수 (여러 줄 항목은 하나로, 체크인과 앱이 남긴 기록은 제외)
+    pub entries: usize,
+    /// 이날 완료한 할 일 수 (`done:` 시각의 날짜 기준)
+    pub todos_done: usize,
+    /// 끝까지 마친 집중 세션 수
+    pub pomodoros: usize,
+}
+
+impl MoodDay {
+    fn average(sum: i64, count: usize) -> Option<f64> {
+        (count > 0).then(|| sum as f64 / count as f64)
+    }
+
+    /// 그날의 평균 기분 점수
+    pub fn mood(&self) -> Option<f64> {
+        Self::average(self.mood_sum, self.mood_count)
+    }
+
+    /// 그날의 평균 에너지
+    pub fn energy(&self) -> Option<f64> {
+        Self::average(self.energy_sum, self.energy_count)
+    }
+
+    /// 그날의 평균 집중도
+    pub fn focus(&self) -> Option<f64> {
+        Self::average(self.focus_sum, self.focus_count)
+    }
+}
+
+/// 날짜별 체크인 기록으로 집계한 기분 추이입니다.
+#[derive(Debug, Clone, Default)]
+pub struct MoodStats {
+    pub days: std::collections::BTreeMap<chrono::NaiveDate, MoodDay>,
+}
+
+impl MoodStats {
+    /// 기분을 기록한 날들에서 하루 기분 점수와 `metric`의 피어슨 상관계수와 표본 수를 계산합니다.
+    /// 표본이 3일 미만이거나 한쪽 값이 모두 같으면 `None`입니다.
+    pub fn mood_correlation(&self, metric: impl Fn(&MoodDay) -> f64) -> Option<(f64, usize)> {
+        let pairs: Vec<(f64, f64)> = self
+            .days
+            .values()
+            .filter_map(|day| Some((day.mood()?, metric(day))))
+            .collect();
+        Some((pearson(&pairs)?, pairs.len()))
+    }
+
+    /// 요일별(월~일) 평균 기분 점수입니다.
+    pub fn weekday_moods(&self) -> [Option<f64>; 7] {
+        use chrono::Datelike;
+
+        let mut sums = [(0.0, 0usize); 7];
+        for (date, day) in &self.days {
+            if let Some(mood) = day.mood() {
+                let slot = &mut sums[date.weekday().num_days_from_monday() as usize];
+                slot.0 += mood;
+                slot.1 += 1;
+            }
+        }
+        sums.map(|(sum, count)| (count > 0).then(|| sum / count as f64))
+    }
+}
+
+/// 피어슨 상관계수 (-1.0 ~ 1.0)
+pub fn pearson(pairs: &[(f64, f64)]) -> Option<f64> {
+    if pairs.len() < 3 {
+        return None;
+    }
+    let n = pairs.len() as f64;
+    let mean_x = pairs.iter().map(|(x, _)| x).sum::<f64>() / n;
+    let mean_y = pairs.iter().map(|(_, y)| y).sum::<f64>() / n;
+    let (mut cov, mut var_x, mut var_y) = (0.0, 0.0, 0.0);
+    for (x, y) in pairs {
+        cov += (x - mean_x) * (y - mean_y);
+        var_x += (x - mean_x).powi(2);
+        var_y += (y - mean_y).powi(2);
+    }
+    if var_x == 0.0 || var_y == 0.0 {
+        return None;
+    }
+    Some(cov / (var_x * var_y).sqrt())
+}
+
+/// Navigate 모드에서 키 입력을 받는 화면 영역(패널)입니다.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FocusPane {
+    /// 왼쪽 로그 목록
+    Logs,
+    /// 오른쪽 오늘의 할 일 목록
+    Tasks,
+}
+
+/// 할 일에 달린 시각 알림(`remind:`) 하나를 나타냅니다.
+#[derive(Clone)]
+pub struct Reminder {
+    /// 알림이 달린 로그 항목
+    pub entry: LogEntry,
+    /// 알림에 표시할 할 일 내용
+    pub text: String,
+    /// 알림이 울릴 시각
+    pub due: chrono::NaiveDateTime,
+}
+
+impl Reminder {
+    /// 미룬 알림을 데몬에 등록할 때 쓰는 키 (공백 없는 고유 문자열)
+    pub fn daemon_key(&self) -> String {
+        let day = std::path::Path::new(&self.entry.file_path)
+            .file_stem()
+            .and_then(|stem| stem.to_str())
+            .unwrap_or_default();
+        format!("remind-{}-{}", day, self.entry.line_number)
+    }
+}
+
+/// 뽀모도로 사이클의 단계입니다.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PomodoroPhase {
+    /// 집중 시간
+    Work,
+    /// 짧은 휴식
+    ShortBreak,
+    /// 긴 휴식 (N번째 집중마다)
+    LongBreak,
+}
+
+impl PomodoroPhase {
+    /// 제목줄 등에 표시할 이름입니다.
+    pub fn label(&self) -> &'static str {
+        match self {
+            PomodoroPhase::Work => "Work",
+            PomodoroPhase::ShortBreak => "Short Break",
+            PomodoroPhase::LongBreak => "Long Break",
+        }
+    }
+
+    /// 단계를 나타내는 이모지입니다.
+    pub fn icon(&self) -> &'static str {
+        match self {
+            PomodoroPhase::Work => "🍅",
+            PomodoroPhase::ShortBreak => "☕",
+            PomodoroPhase::LongBreak => "🌴",
+        }
+    }
+
+    /// 로그에 기록되는 단계 이름입니다. 집중 시간은 이름 없이 기록합니다.
+    pub fn log_name(&self) -> Option<&'static str> {
+        match self {
+            PomodoroPhase::Work => None,
+            PomodoroPhase::ShortBreak => Some("short-break"),
+            PomodoroPhase::LongBreak => Some("long-break"),
+        }
+    }
+}
+
+/// 로그 파일에 `Pomodoro: ...` 형식으로 기록되는 뽀모도로 이벤트입니다.
+#[derive(Debug, Clone, PartialEq)]
+pub enum PomodoroEvent {
+    /// 타이머 시작 (`Pomodoro: 25m started #tag`, `Pomodoro: 5m short-break started`)
+    Started {
+        phase: PomodoroPhase,
+        minutes: i64,
+        tags: Vec<String>,
+    },
+    /// 타이머를 끝까지 마침 (`Pomodoro: completed`, 방해가 있었다면 `Pomodoro: completed int:2 ext:1`)
+    Completed { internal: usize, external: usize },
+    /// 도중에 중단함 (`Pomodoro: stopped`)
+    Stopped,
+    /// 일시정지 (`Pomodoro: paused`)
+    Paused,
+    /// 일시정지 해제 (`Pomodoro: resumed`)
+    Resumed,
+    /// 남은 시간 연장 (`Pomodoro: extended +5m`)
+    Extended { minutes: i64 },
+}
+
+/// 뽀모도로 도중 떠오른 생각(내부) 또는 누군가의 호출(외부)로 인한 방해 기록입니다.
+/// `Interruption: internal 우유 사기` 형식으로 로그에 남고, 휴식이 시작되면 다시 검토합니다.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Interruption {
+    pub external: bool,
+    pub text: String,
+}
+
+impl Interruption {
+    /// 로그에 기록되는 종류 이름
+    pub fn kind(&self) -> &'static str {
+        if self.external {
+            "external"
+        } else {
+            "internal"
+        }
+    }
+
+    /// 화면 표시에 쓰는 아이콘 (내부: 💭, 외부: 📞)
+    pub fn icon(&self) -> &'static str {
+        if self.external { "📞" } else { "💭" }
+    }
+}
+
+/// 뽀모도로와 별개로 여러 개를 동시에 돌릴 수 있는 이름 붙은 타이머입니다.
+/// `end`가 있으면 카운트다운, 없으면 시간을 재는 스톱워치입니다.
+#[derive(Debug, Clone)]
+pub struct NamedTimer {
+    pub name: String,
+    pub started: chrono::DateTime<chrono::Local>,
+    pub end: Option<chrono::DateTime<chrono::Local>>,
+}
+
+impl NamedTimer {
+    /// 표시용 시간: 카운트다운은 남은 시간, 스톱워치는 흐른 시간
+    pub fn display_duration(&self, now: chrono::DateTime<chrono::Local>) -> chrono::Duration {
+        match self.end {
+            Some(end) => (end - now).max(chrono::Duration::zero()),
+            None => now - self.started,
+        }
+    }
+
+    /// 데몬에 등록할 때 쓰는 키 (공백 없는 고유 문자열)
+    pub fn daemon_key(&self) -> String {
+        format!("timer-{}", self.started.timestamp_millis())
+    }
+
+    /// 화면 표시에 쓰는 아이콘 (카운트다운: ⏲, 스톱워치: ⏱)
+    pub fn icon(&self) -> &'static str {
+        if self.end.is_some() { "⏲" } else { "⏱" }
+    }
+}
+
+/// 태그 팝업에 보여줄 계층 태그(`#work/sonomemo/bugs`)의 한 단계입니다.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TagNode {
+    /// 루트부터의 전체 경로 (`#work/sonomemo`)
+    pub path: String,
+    /// 화면에 보일 이름 (루트는 `#work`, 하위는 `sonomemo`)
+    pub name: String,
+    pub depth: usize,
+    /// 이 태그가 직접 붙은 횟수
+    pub count: usize,
+    /// 하위 태그까지 합친 횟수
+    pub total: usize,
+    pub has_children: bool,
+}
+
+impl TagNode {
+    pub fn parent(&self) -> Option<&str> {
+        self.path.rfind('/').map(|index| &self.path[..index])
+    }
+}
+
+/// 태그별 횟수로 계층 트리를 만듭니다.
+/// 깊이 우선 순서로 펼쳐 두며, 형제끼리는 합계가 큰 순(같으면 이름순)입니다.
+/// 검색과 마찬가지로 대소문자는 구분하지 않고 묶으며, 표기는 목록에서 먼저 나온 것을 씁니다.
+pub fn build_tag_tree(tags: &[(String, usize)]) -> Vec<TagNode> {
+    use std::collections::{BTreeSet, HashMap};
+
+    // 소문자 경로 -> (직접 횟수, 하위 포함 합계)
+    let mut counts: HashMap<String, (usize, usize)> = HashMap::new();
+    // 소문자 경로 -> 화면에 보일 경로
+    let mut spellings: HashMap<String, String> = HashMap::new();
+    // 부모 경로(루트는 None) -> 자식 경로 (모두 소문자)
+    let mut children: HashMap<Option<String>, BTreeSet<String>> = HashMap::new();
+
+    for (tag, count) in tags {
+        let mut parent: Option<String> = None;
+        for segment in tag.split('/').filter(|segment| !segment.is_empty()) {
+            let spelling = match &parent {
+                Some(parent) => format!("{}/{}", spellings[parent], segment),
+                None => segment.to_string(),
+            };
+            let key = spelling.to_lowercase();
+            spellings.entry(key.clone()).or_insert(spelling);
+            counts.entry(key.clone()).or_default().1 += count;
+            children
+                .entry(parent.clone())
+                .or_default()
+                .insert(key.clone());
+            parent = Some(key);
+        }
+        if let Some(key) = parent {
+            counts.entry(key).or_default().0 += count;
+        }
+    }
+
+    struct Tree {
+        counts: HashMap<String, (usize, usize)>,
+        spellings: HashMap<String, String>,
+        children: HashMap<Option<String>, BTreeSet<String>>,
+    }
+
+    fn visit(tree: &Tree, parent: Option<String>, depth: usize, nodes: &mut Vec<TagNode>) {
+        let Some(keys) = tree.children.get(&parent) else {
+            return;
+        };
+        let mut keys: Vec<&String> = keys.iter().collect();
+        keys.sort_by(|a, b| {
+            tree.counts[*b]
+                .1
+                .cmp(&tree.counts[*a].1)
+                .then_with(|| a.cmp(b))
+        });
+        for key in keys {
+            let (count, total) = tree.counts[key];
+            let path = &tree.spellings[key];
+            let name = match path.rfind('/') {
+                Some(index) if depth > 0 => path[index + 1..].to_string(),
+                _ => path.clone(),
+            };
+            let child = Some(key.clone());
+            nodes.push(TagNode {
+                path: path.clone(),
+                name,
+                depth,
+                count,
+                total,
+                has_children: tree.children.contains_key(&child),
+            });
+            visit(tree, child, depth + 1, nodes);
+        }
+    }
+
+    let tree = Tree {
+        counts,
+        spellings,
+        children,
+    };
+    let mut nodes = Vec::new();
+    visit(&tree, None, 0, &mut nodes);
+    nodes
+}
+
+/// 하루 중 여러 번 남기는 기분 체크인 기록입니다. (`Mood: 😊 조음 mood:happy energy:4 focus:3`)
+#[derive(Debug, Clone, PartialEq)]
+pub struct Checkin {
+    /// 기분 식별자 (`[[moods]]`의 id). 알 수 없는 예전 기록이면 `None`
+    pub mood_id: Option<String>,
+    /// 에너지 (1~5)
+    pub energy: Option<u8>,
+    /// 집중도 (1~5)
+    pub focus: Option<u8>,
+}
+
+/// 체크인 팝업의 현재 단계입니다.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CheckinStep {
+    Mood,
+    Energy,
+    Focus,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDate;
+
+    #[test]
+    fn test_mood_correlation() {
+        assert_eq!(pearson(&[(1.0, 2.0), (2.0, 4.0)]), None);
+        assert_eq!(pearson(&[(1.0, 1.0), (2.0, 1.0), (3.0, 1.0)]), None);
+        let r = pearson(&[(1.0, 3.0), (2.0, 2.0), (3.0, 1.0)]).unwrap();
+        assert!((r + 1.0).abs() < 1e-9);
+
+        let mut stats = MoodStats::default();
+        // 2025-01-06은 월요일
+        for (offset, mood, entries) in [(0, 2, 10), (1, -2, 2), (7, 1, 6), (8, 0, 4)] {
+            let date =
+                NaiveDate::from_ymd_opt(2025, 1, 6).unwrap() + chrono::Duration::days(offset);
+            stats.days.insert(
+                date,
+                MoodDay {
+                    mood_sum: mood,
+                    mood_count: 1,
+                    entries,
+                    ..Default::default()
+                },
+            );
+        }
+        // 기분 없는 날은 상관계수에서 제외
+        stats.days.insert(
+            NaiveDate::from_ymd_opt(2025, 1, 20).unwrap(),
+            MoodDay {
+                entries: 50,
+                ..Default::default()
+            },
+        );
+
+        let (r, n) = stats.mood_correlation(|d| d.entries as f64).unwrap();
+        assert_eq!(n, 4);
+        assert!(r > 0.9);
+
+        let weekdays = stats.weekday_moods();
+        assert_eq!(weekdays[0], Some(1.5));
+        assert_eq!(weekdays[1], Some(-1.0));
+        assert_eq!(weekdays[2], None);
+    }
+
+    #[test]
+    fn test_build_tag_tree() {
+        let tags = [
+            ("#work/sonomemo/bugs".to_string(), 3),
+            ("#life".to_string(), 4),
+            ("#work".to_string(), 1),
+            ("#work/meeting".to_string(), 2),
+            ("#work/sonomemo".to_string(), 1),
+        ];
+        let tree = build_tag_tree(&tags);
+        let rows: Vec<(&str, &str, usize, usize, usize, bool)> = tree
+            .iter()
+            .map(|n| {
+                (
+                    n.path.as_str(),
+                    n.name.as_str(),
+                    n.depth,
+                    n.count,
+                    n.total,
+                    n.has_children,
+                )
+            })
+            .collect();
+        assert_eq!(
+            rows,
+            vec![
+                ("#work", "#work", 0, 1, 7, true),
+                ("#work/sonomemo", "sonomemo", 1, 1, 4, true),
+                ("#work/sonomemo/bugs", "bugs", 2, 3, 3, false),
+                ("#work/meeting", "meeting", 1, 2, 2, false),
+                ("#life", "#life", 0, 4, 4, false),
+            ]
+        );
+        assert_eq!(tree[2].parent(), Some("#work/sonomemo"));
+        assert_eq!(tree[0].parent(), None);
+    }
+
+    #[test]
+    fn test_build_tag_tree_ignores_case() {
+        // 검색(`tag_matches`)처럼 대소문자가 다른 태그는 한 갈래로 묶고 합계도 합침
+        let tags = [
+            ("#Work/a".to_string(), 2),
+            ("#work/b".to_string(), 1),
+            ("#WORK/A".to_string(), 3),
+        ];
+        let tree = build_tag_tree(&tags);
+        let rows: Vec<(&str, usize, usize)> = tree
+            .iter()
+            .map(|n| (n.path.as_str(), n.depth, n.total))
+            .collect();
+        assert_eq!(
+            rows,
+            vec![("#Work", 0, 6), ("#Work/a", 1, 5), ("#Work/b", 1, 1)]
+        );
+    }
+
+    #[test]
+    fn test_streak() {
+        let d = |day| NaiveDate::from_ymd_opt(2025, 1, day).unwrap();
+        let days = [d(1), d(2), d(3), d(4), d(7), d(8)];
+
+        // 오늘 아직 기록이 없어도 어제까지 이어졌으면 유지
+        assert_eq!(
+            Streak::from_days(days, d(9)),
+            Streak {
+                current: 2,
+                longest: 4
+            }
+        );
+        assert_eq!(Streak::from_days(days, d(8)).current, 2);
+        assert_eq!(Streak::from_days(days, d(10)).current, 0);
+        assert_eq!(Streak::from_days([], d(10)), Streak::default());
+    }
+}
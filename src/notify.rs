@@ -0,0 +1,73 @@
+use std::io::{self, Write};
+
+/// 터미널 벨(BEL)을 `count`번 울립니다.
+/// 터미널 설정에 따라 소리 대신 화면 깜빡임 등으로 표시될 수 있습니다.
+pub fn ring_bell(count: usize) {
+    if count == 0 {
+        return;
+    }
+    let mut stdout = io::stdout();
+    let _ = stdout.write_all("\x07".repeat(count).as_bytes());
+    let _ = stdout.flush();
+}
+
+/// 터미널 이스케이프 시퀀스로 데스크톱 알림을 보냅니다. 별도 서비스 없이 동작합니다.
+/// - `"osc9"`: iTerm2, WezTerm, Windows Terminal 등 (`ESC ] 9 ; 메시지 BEL`)
+/// - `"osc777"`: rxvt, foot, Ghostty 등 (`ESC ] 777 ; notify ; 제목 ; 본문 BEL`)
+///
+/// 그 외 값(`"none"` 포함)이면 아무것도 하지 않습니다.
+pub fn desktop_notify(kind: &str, title: &str, body: &str) {
+    let Some(sequence) = desktop_sequence(kind, title, body) else {
+        return;
+    };
+    let mut stdout = io::stdout();
+    let _ = stdout.write_all(sequence.as_bytes());
+    let _ = stdout.flush();
+}
+
+/// 데스크톱 알림 이스케이프 시퀀스를 만듭니다. 데몬이 등록된 다른 터미널로 보낼 때도 사용합니다.
+pub fn desktop_sequence(kind: &str, title: &str, body: &str) -> Option<String> {
+    match kind.to_lowercase().as_str() {
+        "osc9" => Some(format!(
+            "\x1b]9;{}: {}\x07",
+            sanitize(title),
+            sanitize(body)
+        )),
+        "osc777" => Some(format!(
+            "\x1b]777;notify;{};{}\x07",
+            sanitize(title),
+            sanitize(body)
+        )),
+        _ => None,
+    }
+}
+
+/// 시퀀스를 깨뜨릴 수 있는 제어 문자와 구분자(`;`)를 제거합니다.
+fn sanitize(text: &str) -> String {
+    text.chars()
+        .filter(|c| !c.is_control())
+        .map(|c| if c == ';' { ',' } else { c })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_desktop_sequence_sanitizes_message() {
+        let title = "Pomodoro\x1b]9;x";
+        let body = "끝;\x07다음\n휴식\x1b[31m";
+
+        // 본문의 ESC/BEL/줄바꿈은 지우고 `;`는 `,`로 바꿔서 시퀀스가 중간에 끊기지 않게 함
+        assert_eq!(
+            desktop_sequence("osc9", title, body).unwrap(),
+            "\x1b]9;Pomodoro]9,x: 끝,다음휴식[31m\x07"
+        );
+        assert_eq!(
+            desktop_sequence("OSC777", title, body).unwrap(),
+            "\x1b]777;notify;Pomodoro]9,x;끝,다음휴식[31m\x07"
+        );
+        assert_eq!(desktop_sequence("none", title, body), None);
+    }
+}
@@ -1,8 +1,43 @@
-use crate::models::LogEntry;
-use chrono::Local;
+use crate::config::MoodConfig;
+use crate::models::{
+    AppState, DayActivity, HourStats, LogEntry, MoodStats, PomodoroEvent, PomodoroPhase,
+    PomodoroStats, Reminder, TodoCycleStats,
+};
+use chrono::{Local, NaiveDate, NaiveDateTime};
+use std::collections::{BTreeMap, HashSet};
 use std::fs::{self, OpenOptions};
 use std::io::{self, Write};
 use std::path::PathBuf;
+use std::time::SystemTime;
+
+/// 달성 기록을 저장하는 파일 이름 (로그 폴더 안)
+const STATE_FILE: &str = "state.toml";
+
+/// 할 일 이월 확인이 끝났음을 나타내는 시스템 마커입니다.
+const CARRYOVER_MARKER: &str = "System: Carryover Checked";
+/// 반복 할 일을 오늘 로그에 추가했음을 나타내는 시스템 마커입니다. (할 일마다 한 줄)
+const RECURRING_MARKER: &str = "System: Recurring Checked";
+/// 처리한 반복 할 일의 내용을 마커 뒤에 붙일 때의 구분자
+const RECURRING_TASK_SEPARATOR: &str = ": ";
+
+/// 화면이나 통계에 노출되지 않아야 하는 시스템 마커 라인인지 확인합니다.
+fn is_system_line(line: &str) -> bool {
+    line.contains(CARRYOVER_MARKER) || line.contains(RECURRING_MARKER)
+}
+
+/// 앱이 자동으로 남긴 기록(`Pomodoro:`, `Focus:`, `Timer:`, `Interruption:`)인지 확인합니다.
+/// 직접 쓴 기록이 아니므로 활동량이나 목표 달성에는 세지 않습니다.
+pub fn is_generated_entry(content: &str) -> bool {
+    let body = parser::entry_body(content);
+    [
+        parser::POMODORO_PREFIX,
+        parser::FOCUS_PREFIX,
+        parser::TIMER_PREFIX,
+        parser::INTERRUPTION_PREFIX,
+    ]
+    .iter()
+    .any(|prefix| body.starts_with(prefix))
+}
 
 /// 지정된 경로에 로그 디렉토리가 존재하는지 확인하고, 없으면 생성합니다.
 pub fn ensure_log_dir(log_path: &str) -> io::Result<()> {
@@ -13,11 +48,39 @@ pub fn ensure_log_dir(log_path: &str) -> io::Result<()> {
     Ok(())
 }
 
+/// 로그 파일 경로의 파일명(YYYY-MM-DD)에서 날짜를 읽어옵니다.
+fn file_date(path: &str) -> Option<NaiveDate> {
+    let stem = std::path::Path::new(path).file_stem()?.to_str()?;
+    NaiveDate::parse_from_str(stem, "%Y-%m-%d").ok()
+}
+
+/// 로그 디렉토리의 모든 일자별 로그 파일을 (날짜, 경로) 형태로 날짜순 정렬해 반환합니다.
+fn dated_log_files(log_path: &str) -> io::Result<Vec<(NaiveDate, PathBuf)>> {
+    ensure_log_dir(log_path)?;
+    let mut files = Vec::new();
+    if let Ok(entries) = fs::read_dir(log_path) {
+        for entry in entries.flatten() {
+            let path = entry.path();
+            if path.extension().and_then(|s| s.to_str()) == Some("md")
+                && let Some(date) = file_date(&path.to_string_lossy())
+            {
+                files.push((date, path));
+            }
+        }
+    }
+    files.sort();
+    Ok(files)
+}
+
 /// 오늘 날짜에 해당하는 로그 파일의 경로를 생성합니다.
 fn get_today_file_path(log_path: &str) -> PathBuf {
-    let today = Local::now().format("%Y-%m-%d").to_string();
+    get_file_path(log_path, Local::now().date_naive())
+}
+
+/// 특정 날짜의 로그 파일 경로를 생성합니다.
+fn get_file_path(log_path: &str, date: NaiveDate) -> PathBuf {
     let mut path = PathBuf::from(log_path);
-    path.push(format!("{}.md", today));
+    path.push(format!("{}.md", date.format("%Y-%m-%d")));
     path
 }
 
@@ -25,8 +88,13 @@ fn get_today_file_path(log_path: &str) -> PathBuf {
 ///
 /// `content`: 추가할 로그 내용
 pub fn append_entry(log_path: &str, content: &str) -> io::Result<()> {
+    append_entry_on(log_path, Local::now().date_naive(), content)
+}
+
+/// 특정 날짜의 로그 파일에 새로운 항목을 추가합니다. (지난 날짜 편집용, 시각은 현재 시각)
+pub fn append_entry_on(log_path: &str, date: NaiveDate, content: &str) -> io::Result<()> {
     ensure_log_dir(log_path)?;
-    let path = get_today_file_path(log_path);
+    let path = get_file_path(log_path, date);
 
     let time = Local::now().format("%H:%M:%S").to_string();
     let line = format!("[{}] {}\n", time, content);
@@ -39,8 +107,13 @@ pub fn append_entry(log_path: &str, content: &str) -> io::Result<()> {
 
 /// 오늘 작성된 모든 로그 항목을 읽어옵니다.
 pub fn read_today_entries(log_path: &str) -> io::Result<Vec<LogEntry>> {
+    read_entries_on(log_path, Local::now().date_naive())
+}
+
+/// 특정 날짜에 작성된 모든 로그 항목을 읽어옵니다.
+pub fn read_entries_on(log_path: &str, date: NaiveDate) -> io::Result<Vec<LogEntry>> {
     ensure_log_dir(log_path)?;
-    let path = get_today_file_path(log_path);
+    let path = get_file_path(log_path, date);
 
     if !path.exists() {
         return Ok(Vec::new());
@@ -52,11 +125,19 @@ pub fn read_today_entries(log_path: &str) -> io::Result<Vec<LogEntry>> {
     Ok(parse_log_content(&content, &path_str))
 }
 
+/// 특정 날짜 로그 파일의 마지막 수정 시각을 반환합니다. 파일이 없으면 `None`입니다.
+pub fn modified_on(log_path: &str, date: NaiveDate) -> Option<SystemTime> {
+    fs::metadata(get_file_path(log_path, date))
+        .and_then(|meta| meta.modified())
+        .ok()
+}
+
 /// 모든 로그 파일에서 검색어(`query`)가 포함된 항목을 찾습니다.
 pub fn search_entries(log_path: &str, query: &str) -> io::Result<Vec<LogEntry>> {
     ensure_log_dir(log_path)?;
     let dir = PathBuf::from(log_path);
     let mut results = Vec::new();
+    let tag_query = parser::is_tag_query(query);
 
     if let Ok(entries) = fs::read_dir(dir) {
         for entry in entries.flatten() {
@@ -72,7 +153,13 @@ pub fn search_entries(log_path: &str, query: &str) -> io::Result<Vec<LogEntry>>
                 if let Ok(content) = fs::read_to_string(&path) {
                     let parsed_entries = parse_log_content(&content, &path_str);
                     for entry in parsed_entries {
-                        if entry.content.contains(query) {
+                        // 태그 하나로 찾으면 하위 태그(`#work` → `#work/bugs`)까지 포함
+                        let matched = if tag_query {
+                            parser::has_tag(&entry.content, query)
+                        } else {
+                            entry.content.contains(query)
+                        };
+                        if matched {
                             // 날짜 정보 추가
                             let display_content = format!("[{}] {}", date_str, entry.content);
 
@@ -97,7 +184,7 @@ fn parse_log_content(content: &str, path_str: &str) -> Vec<LogEntry> {
     let mut entries: Vec<LogEntry> = Vec::new();
 
     for (i, line) in content.lines().enumerate() {
-        if line.contains("System: Carryover Checked") {
+        if is_system_line(line) {
             continue;
         }
 
@@ -128,12 +215,31 @@ use crate::ui::parser;
 /// 특정 로그 항목의 할 일(체크박스) 상태를 토글합니다.
 /// 해당 파일의 정확한 라인을 찾아 내용을 수정합니다.
 pub fn toggle_todo_status(entry: &LogEntry) -> io::Result<()> {
+    // Use shared parser logic to toggle (완료 시 done: 시각 주석 추가)
+    let entry_date = file_date(&entry.file_path).unwrap_or_else(|| Local::now().date_naive());
+    let stamp = parser::format_done_stamp(Local::now().naive_local(), entry_date);
+    rewrite_entry_line(entry, |line| {
+        parser::toggle_checkbox_with_stamp(line, &stamp)
+    })
+}
+
+/// 특정 로그 항목의 시각 알림을 확인 처리하여 다시 울리지 않도록 파일에 기록합니다.
+pub fn dismiss_reminder(entry: &LogEntry) -> io::Result<()> {
+    rewrite_entry_line(entry, parser::mark_reminder_dismissed)
+}
+
+/// 할 일 항목의 `spent:` 주석에 실제 사용 시간(분)을 더해 파일에 기록합니다.
+pub fn add_spent_minutes(entry: &LogEntry, minutes: i64) -> io::Result<()> {
+    rewrite_entry_line(entry, |line| parser::add_spent(line, minutes))
+}
+
+/// 로그 항목의 첫 줄을 `f`로 변환한 결과로 바꿔 파일에 다시 씁니다.
+fn rewrite_entry_line(entry: &LogEntry, f: impl FnOnce(&str) -> String) -> io::Result<()> {
     let content = fs::read_to_string(&entry.file_path)?;
     let mut lines: Vec<String> = content.lines().map(|s| s.to_string()).collect();
 
     if entry.line_number < lines.len() {
-        // Use shared parser logic to toggle
-        lines[entry.line_number] = parser::toggle_checkbox(&lines[entry.line_number]);
+        lines[entry.line_number] = f(&lines[entry.line_number]);
     }
 
     let mut new_content = lines.join("\n");
@@ -147,8 +253,41 @@ pub fn toggle_todo_status(entry: &LogEntry) -> io::Result<()> {
     Ok(())
 }
 
+/// 같은 파일에 있는 두 로그 항목의 위치를 맞바꿉니다 (여러 줄 항목은 통째로 이동).
+/// 할 일 목록의 순서를 바꿀 때 사용되며, 서로 다른 파일의 항목이면 아무것도 하지 않습니다.
+pub fn swap_entries(a: &LogEntry, b: &LogEntry) -> io::Result<()> {
+    if a.file_path != b.file_path || a.line_number == b.line_number {
+        return Ok(());
+    }
+    let (first, second) = if a.line_number < b.line_number {
+        (a, b)
+    } else {
+        (b, a)
+    };
+
+    let content = fs::read_to_string(&first.file_path)?;
+    let lines: Vec<&str> = content.lines().collect();
+
+    let first_end = first.line_number + first.content.lines().count().max(1);
+    let second_end = second.line_number + second.content.lines().count().max(1);
+    if first_end > second.line_number || second_end > lines.len() {
+        return Ok(());
+    }
+
+    let mut new_lines = Vec::with_capacity(lines.len());
+    new_lines.extend_from_slice(&lines[..first.line_number]);
+    new_lines.extend_from_slice(&lines[second.line_number..second_end]);
+    new_lines.extend_from_slice(&lines[first_end..second.line_number]);
+    new_lines.extend_from_slice(&lines[first.line_number..first_end]);
+    new_lines.extend_from_slice(&lines[second_end..]);
+
+    let mut new_content = new_lines.join("\n");
+    new_content.push('\n');
+    fs::write(&first.file_path, new_content)
+}
+
 /// 가장 최근(오늘 제외) 로그 파일에서 완료되지 않은 할 일 목록을 가져옵니다.
-/// 이월(carryover) 기능을 위해 사용됩니다.
+/// 이월(carryover) 기능을 위해 사용되며, 오늘 로그에 이미 있는 할 일(예: 반복 할 일)은 제외합니다.
 pub fn get_last_file_pending_todos(log_path: &str) -> io::Result<Vec<String>> {
     ensure_log_dir(log_path)?;
     let dir = PathBuf::from(log_path);
@@ -168,12 +307,24 @@ pub fn get_last_file_pending_todos(log_path: &str) -> io::Result<Vec<String>> {
         file_paths.sort();
 
         if let Some(last_path) = file_paths.last() {
+            let existing = get_today_todo_contents(log_path)?;
             let mut todos = Vec::new();
             if let Ok(content) = fs::read_to_string(last_path) {
                 for line in content.lines() {
                     // Use shared parser logic to extract pending todos
-                    if let Some(todo_content) = parser::extract_pending_content(line) {
-                        todos.push(todo_content);
+                    if let Some(todo_content) = parser::extract_pending_content(line)
+                        && !existing.contains(&todo_content)
+                    {
+                        // 누적된 사용 시간(spent:)은 이월 후에도 이어서 쌓이도록 함께 가져감
+                        todos.push(match parser::parse_spent(line) {
+                            Some(spent) => format!(
+                                "{} {}{}",
+                                todo_content,
+                                parser::SPENT_PREFIX,
+                                parser::format_duration(spent)
+                            ),
+                            None => todo_content,
+                        });
                     }
                 }
             }
@@ -183,36 +334,65 @@ pub fn get_last_file_pending_todos(log_path: &str) -> io::Result<Vec<String>> {
     Ok(Vec::new())
 }
 
-/// 모든 로그 파일에서 태그('#')를 추출하여 빈도수 순으로 정렬해 반환합니다.
+/// 모든 로그 파일에서 태그('#')를 추출하여 (태그, 사용 횟수)를 반환합니다.
+///
+/// 자주, 그리고 최근에 쓴 태그가 앞에 오도록 정렬합니다.
+/// 각 사용은 30일마다 절반씩 가중치가 줄어들며, 가중치 합이 큰 순서입니다.
 pub fn get_all_tags(log_path: &str) -> io::Result<Vec<(String, usize)>> {
     use std::collections::HashMap;
 
-    ensure_log_dir(log_path)?;
-    let dir = PathBuf::from(log_path);
-    let mut tag_counts = HashMap::new();
+    const HALF_LIFE_DAYS: f64 = 30.0;
+    let today = Local::now().date_naive();
+    // 태그 -> (사용 횟수, 최근성 가중치 합)
+    let mut tag_stats: HashMap<String, (usize, f64)> = HashMap::new();
 
-    if let Ok(entries) = fs::read_dir(dir) {
-        for entry in entries.flatten() {
-            let path = entry.path();
-            if path.extension().and_then(|s| s.to_str()) == Some("md")
-                && let Ok(content) = fs::read_to_string(&path)
-            {
-                for line in content.lines() {
-                    for word in line.split_whitespace() {
-                        if word.starts_with('#') && word.len() > 1 {
-                            *tag_counts.entry(word.to_string()).or_insert(0) += 1;
-                        }
-                    }
+    for (date, path) in dated_log_files(log_path)? {
+        let Ok(content) = fs::read_to_string(&path) else {
+            continue;
+        };
+        let age = (today - date).num_days().max(0) as f64;
+        let weight = 0.5f64.powf(age / HALF_LIFE_DAYS);
+        for line in content.lines().filter(|line| !is_system_line(line)) {
+            for token in parser::tokenize(line) {
+                // `#work/` 같은 끝 구분자는 정리해서 같은 태그로 셉니다
+                if let parser::LogToken::Tag(word) = token
+                    && let Some(tag) = parser::normalize_tag(word)
+                {
+                    let stat = tag_stats.entry(tag).or_default();
+                    stat.0 += 1;
+                    stat.1 += weight;
                 }
             }
         }
     }
 
-    let mut tags: Vec<(String, usize)> = tag_counts.into_iter().collect();
-    // 많이 쓰인 순서대로 정렬 (내림차순)
-    tags.sort_by(|a, b| b.1.cmp(&a.1));
+    let mut tags: Vec<(String, (usize, f64))> = tag_stats.into_iter().collect();
+    tags.sort_by(|a, b| {
+        b.1.1
+            .total_cmp(&a.1.1)
+            .then_with(|| b.1.0.cmp(&a.1.0))
+            .then_with(|| a.0.cmp(&b.0))
+    });
+
+    Ok(tags
+        .into_iter()
+        .map(|(tag, (count, _))| (tag, count))
+        .collect())
+}
+
+/// 로그 폴더의 `state.toml`에서 달성 기록을 읽습니다. 없거나 깨져 있으면 빈 기록입니다.
+pub fn load_state(log_path: &str) -> AppState {
+    fs::read_to_string(PathBuf::from(log_path).join(STATE_FILE))
+        .ok()
+        .and_then(|content| toml::from_str(&content).ok())
+        .unwrap_or_default()
+}
 
-    Ok(tags)
+/// 달성 기록을 로그 폴더의 `state.toml`에 저장합니다.
+pub fn save_state(log_path: &str, state: &AppState) -> io::Result<()> {
+    ensure_log_dir(log_path)?;
+    let content = toml::to_string_pretty(state).map_err(io::Error::other)?;
+    fs::write(PathBuf::from(log_path).join(STATE_FILE), content)
 }
 
 /// 오늘 할 일 이월 작업이 이미 수행되었는지 확인합니다.
@@ -223,42 +403,568 @@ pub fn is_carryover_done(log_path: &str) -> io::Result<bool> {
         return Ok(false);
     }
     let content = fs::read_to_string(path)?;
-    Ok(content.contains("System: Carryover Checked"))
+    Ok(content.contains(CARRYOVER_MARKER))
 }
 
 /// 오늘 할 일 이월 작업이 완료되었음을 시스템 마커로 기록합니다.
 pub fn mark_carryover_done(log_path: &str) -> io::Result<()> {
-    append_entry(log_path, "System: Carryover Checked")
+    append_entry(log_path, CARRYOVER_MARKER)
+}
+
+/// 오늘 로그 파일에 이미 존재하는 할 일들의 내용을 (완료 여부와 무관하게) 수집합니다.
+fn get_today_todo_contents(log_path: &str) -> io::Result<HashSet<String>> {
+    get_todo_contents_on(log_path, Local::now().date_naive())
+}
+
+/// `date` 날짜의 로그 파일에 이미 존재하는 할 일들의 내용을 (완료 여부와 무관하게) 수집합니다.
+fn get_todo_contents_on(log_path: &str, date: NaiveDate) -> io::Result<HashSet<String>> {
+    let path = get_file_path(log_path, date);
+    if !path.exists() {
+        return Ok(HashSet::new());
+    }
+    let content = fs::read_to_string(path)?;
+    Ok(content
+        .lines()
+        .filter_map(parser::extract_todo_content)
+        .map(|(_, todo)| todo)
+        .collect())
 }
 
-/// 날짜별 활동(로그 수) 통계를 수집합니다.
+/// 설정된 반복 할 일 중 `date`에 해당하는 항목을 그날 로그에 새 할 일로 추가합니다.
+/// 할 일마다 처리 마커(`System: Recurring Checked: 내용`)를 남겨 하루에 한 번만 추가하며,
+/// 같은 내용의 할 일이 이미 있으면 건너뜁니다. 그날 나중에 설정에 추가한 할 일도 추가됩니다.
+pub fn insert_recurring_todos(log_path: &str, tasks: &[String], date: NaiveDate) -> io::Result<()> {
+    if tasks.is_empty() {
+        return Ok(());
+    }
+
+    ensure_log_dir(log_path)?;
+    let path = get_file_path(log_path, date);
+    let content = if path.exists() {
+        fs::read_to_string(&path)?
+    } else {
+        String::new()
+    };
+
+    let mut processed = HashSet::new();
+    for line in content.lines() {
+        if let Some((_, rest)) = line.split_once(RECURRING_MARKER)
+            && let Some(task) = rest.strip_prefix(RECURRING_TASK_SEPARATOR)
+        {
+            processed.insert(task.to_string());
+        }
+    }
+
+    let mut existing = get_todo_contents_on(log_path, date)?;
+
+    for task in tasks {
+        if let Some((content, recurrence)) = parser::parse_recurrence(task)
+            && recurrence.matches(date)
+            && processed.insert(content.clone())
+        {
+            if existing.insert(content.clone()) {
+                append_entry_on(log_path, date, &parser::format_todo(&content, false))?;
+            }
+            append_entry_on(
+                log_path,
+                date,
+                &format!(
+                    "{}{}{}",
+                    RECURRING_MARKER, RECURRING_TASK_SEPARATOR, content
+                ),
+            )?;
+        }
+    }
+
+    Ok(())
+}
+
+/// 완료한 할 일이라면 완료한 날짜를 반환합니다.
+/// `done:` 시각이 있으면 그 날짜, 없으면(예전 기록) 로그 파일의 날짜입니다.
+fn completed_date(content: &str, date: NaiveDate) -> Option<NaiveDate> {
+    let (true, _) = parser::extract_todo_content(content)? else {
+        return None;
+    };
+    Some(parser::parse_done_stamp(content, date).map_or(date, |done| done.date()))
+}
+
+/// 날짜별 활동 통계를 수집합니다.
 /// "잔디밭(contribution graph)" 시각화를 위해 사용됩니다.
-pub fn get_activity_stats(log_path: &str) -> io::Result<std::collections::HashMap<String, usize>> {
+///
+/// 줄 단위가 아니라 파싱된 로그 항목 단위로 세며, 체크인(`Mood: ...`)은 활동에서 제외합니다.
+/// 이월된 할 일은 처음 적은 날에만 "새 할 일"로 세고, 완료한 할 일은 `done:` 시각의 날짜에 셉니다.
+pub fn get_activity_stats(log_path: &str) -> io::Result<BTreeMap<NaiveDate, DayActivity>> {
+    let mut stats: BTreeMap<NaiveDate, DayActivity> = BTreeMap::new();
+    // 직전 파일에 남아 있던(이월 대상) 할 일 내용
+    let mut open_todos: HashSet<String> = HashSet::new();
+
+    for (date, path) in dated_log_files(log_path)? {
+        let Ok(content) = fs::read_to_string(&path) else {
+            continue;
+        };
+        let path_str = path.to_string_lossy();
+        let mut tags = HashSet::new();
+        let mut pending = HashSet::new();
+        let mut completed_on = Vec::new();
+        let day = stats.entry(date).or_default();
+
+        for entry in parse_log_content(&content, &path_str) {
+            if parser::parse_checkin(&entry.content).is_some() || is_generated_entry(&entry.content)
+            {
+                continue;
+            }
+            day.entries += 1;
+
+            let body = match parser::extract_todo_content(&entry.content) {
+                Some((checked, todo)) => {
+                    if !checked {
+                        pending.insert(todo.clone());
+                    }
+                    if !open_todos.contains(&todo) {
+                        day.todos_created += 1;
+                    }
+                    completed_on.extend(completed_date(&entry.content, date));
+                    todo
+                }
+                None => parser::entry_body(&entry.content).to_string(),
+            };
+            day.words += body.split_whitespace().count();
+            // 태그 트리와 같이 `#Work`, `#work/`는 같은 태그로 셈
+            tags.extend(parser::tokenize(&body).into_iter().filter_map(|token| {
+                let parser::LogToken::Tag(word) = token else {
+                    return None;
+                };
+                parser::normalize_tag(word).map(|tag| tag.to_lowercase())
+            }));
+        }
+        day.tags = tags.len();
+
+        for done in completed_on {
+            stats.entry(done).or_default().todos_completed += 1;
+        }
+        open_todos = pending;
+    }
+
+    for (date, (_, minutes)) in get_pomodoro_stats(log_path)?.daily {
+        stats.entry(date).or_default().pomodoro_minutes = minutes.max(0) as usize;
+    }
+
+    Ok(stats)
+}
+
+/// 할 일의 생성 시각부터 완료 시각(`done:`)까지의 사이클 타임 통계를 계산합니다.
+///
+/// 이월(carryover)된 할 일은 연속된 로그 파일에 같은 내용으로 다시 등장하므로,
+/// 처음 등장한 시각을 생성 시각으로 보고 완료될 때까지 추적합니다.
+pub fn get_todo_cycle_stats(log_path: &str) -> io::Result<TodoCycleStats> {
     use std::collections::HashMap;
 
-    ensure_log_dir(log_path)?;
-    let dir = PathBuf::from(log_path);
-    let mut stats = HashMap::new();
+    let mut stats = TodoCycleStats::default();
+    // 아직 완료되지 않은 할 일: 내용 -> 최초 생성 시각
+    let mut open: HashMap<String, NaiveDateTime> = HashMap::new();
 
-    if let Ok(entries) = fs::read_dir(dir) {
-        for entry in entries.flatten() {
-            let path = entry.path();
-            if path.extension().and_then(|s| s.to_str()) == Some("md")
-                && let Some(filename) = path.file_stem().and_then(|s| s.to_str())
+    for (date, path) in dated_log_files(log_path)? {
+        let Ok(content) = fs::read_to_string(&path) else {
+            continue;
+        };
+        let mut seen = HashSet::new();
+
+        for line in content.lines() {
+            let Some((checked, todo)) = parser::extract_todo_content(line) else {
+                continue;
+            };
+            let created = parser::parse_entry_time(line, date)
+                .unwrap_or_else(|| date.and_hms_opt(0, 0, 0).unwrap_or_default());
+
+            if !checked {
+                open.entry(todo.clone()).or_insert(created);
+                seen.insert(todo);
+                continue;
+            }
+
+            // 예상 시간과 실제 사용 시간이 모두 있는 할 일은 예측 정확도에 반영
+            if let Some(estimate) = parser::parse_estimate(line)
+                && let Some(spent) = parser::parse_spent(line)
+                && estimate > 0
             {
-                // 파일명(YYYY-MM-DD)을 키로 사용
-                if let Ok(content) = fs::read_to_string(&path) {
-                    // 빈 줄이나 시스템 마커 제외하고 카운트
-                    let count = content
-                        .lines()
-                        .filter(|l| {
-                            !l.trim().is_empty() && !l.contains("System: Carryover Checked")
-                        })
-                        .count();
-                    stats.insert(filename.to_string(), count);
+                stats.estimated += 1;
+                stats.estimate_minutes += estimate;
+                stats.spent_minutes += spent;
+                if (spent - estimate).abs() * 4 <= estimate {
+                    stats.accurate += 1;
+                }
+            }
+
+            let started = open.remove(&todo).unwrap_or(created);
+            if let Some(done) = parser::parse_done_stamp(line, date) {
+                let minutes = (done - started).num_minutes();
+                if minutes >= 0 {
+                    stats.completed += 1;
+                    stats.total_minutes += minutes;
+                    stats.longest_minutes = stats.longest_minutes.max(minutes);
+                }
+            }
+        }
+
+        // 다음 파일로 이월되지 않은(사라진) 할 일은 추적을 멈춤
+        open.retain(|todo, _| seen.contains(todo));
+    }
+
+    Ok(stats)
+}
+
+/// 모든 로그 파일의 뽀모도로 기록(`Pomodoro: ...`)을 모아 집중 통계를 계산합니다.
+/// 집중 시간은 끝까지 마친 세션의 설정 시간에 연장한 시간을 더한 값입니다.
+pub fn get_pomodoro_stats(log_path: &str) -> io::Result<PomodoroStats> {
+    use std::collections::HashMap;
+
+    let mut stats = PomodoroStats::default();
+    let mut tag_minutes: HashMap<String, i64> = HashMap::new();
+
+    for (date, path) in dated_log_files(log_path)? {
+        let Ok(content) = fs::read_to_string(&path) else {
+            continue;
+        };
+        // 진행 중인 집중 세션: (시간(분), 태그)
+        let mut session: Option<(i64, Vec<String>)> = None;
+
+        for line in content.lines() {
+            match parser::parse_pomodoro_entry(line) {
+                Some(PomodoroEvent::Started {
+                    phase,
+                    minutes,
+                    tags,
+                }) => {
+                    session = None;
+                    if phase == PomodoroPhase::Work {
+                        stats.started += 1;
+                        session = Some((minutes, tags));
+                    }
+                }
+                Some(PomodoroEvent::Extended { minutes }) => {
+                    if let Some((total, _)) = &mut session {
+                        *total += minutes;
+                    }
+                }
+                Some(PomodoroEvent::Completed { internal, external }) => {
+                    if let Some((minutes, tags)) = session.take() {
+                        stats.completed += 1;
+                        stats.interruptions += internal + external;
+                        let day = stats.daily.entry(date).or_default();
+                        day.0 += 1;
+                        day.1 += minutes;
+                        for tag in tags {
+                            *tag_minutes.entry(tag).or_default() += minutes;
+                        }
+                    }
+                }
+                Some(PomodoroEvent::Stopped) => session = None,
+                _ => {}
+            }
+        }
+    }
+
+    let mut tag_minutes: Vec<(String, i64)> = tag_minutes.into_iter().collect();
+    tag_minutes.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+    stats.tag_minutes = tag_minutes;
+
+    Ok(stats)
+}
+
+/// 모든 로그의 타임스탬프로 요일 × 시간대 분포를 집계합니다.
+/// `tag`가 비어 있지 않으면 그 태그가 붙은 항목만 셉니다.
+pub fn get_hour_stats(log_path: &str, tag: &str) -> io::Result<HourStats> {
+    use chrono::{Datelike, Timelike};
+
+    let mut stats = HourStats::default();
+
+    for (date, path) in dated_log_files(log_path)? {
+        let Ok(content) = fs::read_to_string(&path) else {
+            continue;
+        };
+        let weekday = date.weekday().num_days_from_monday() as usize;
+
+        for entry in parse_log_content(&content, &path.to_string_lossy()) {
+            if !tag.trim().is_empty() && !parser::has_tag(&entry.content, tag) {
+                continue;
+            }
+            if parser::parse_checkin(&entry.content).is_some() {
+                continue;
+            }
+            if let Some(hour) = parser::parse_entry_hour(&entry.content) {
+                if !is_generated_entry(&entry.content) {
+                    stats.entries[weekday][hour as usize] += 1;
+                }
+                if let Some(PomodoroEvent::Started {
+                    phase: PomodoroPhase::Work,
+                    ..
+                }) = parser::parse_pomodoro_entry(&entry.content)
+                {
+                    stats.focus[weekday][hour as usize] += 1;
                 }
             }
+            if let Some((true, _)) = parser::extract_todo_content(&entry.content)
+                && let Some(done) = parser::parse_done_stamp(&entry.content, date)
+            {
+                let day = done.date().weekday().num_days_from_monday() as usize;
+                stats.todos_done[day][done.hour() as usize] += 1;
+            }
         }
     }
+
     Ok(stats)
 }
+
+/// 모든 로그 파일의 체크인 기록(`Mood: ...`)과 그날의 활동량을 날짜별로 모읍니다.
+/// 기분 점수는 `[[moods]]`의 `valence`를 사용하며, 점수가 없는 기분은 점수 집계에서 빠집니다.
+/// 완료한 할 일은 활동 통계와 같이 `done:` 시각의 날짜에 셉니다.
+pub fn get_mood_stats(log_path: &str, moods: &[MoodConfig]) -> io::Result<MoodStats> {
+    let mut stats = MoodStats::default();
+
+    for (date, path) in dated_log_files(log_path)? {
+        let Ok(content) = fs::read_to_string(&path) else {
+            continue;
+        };
+        let path_str = path.to_string_lossy();
+        let mut completed_on = Vec::new();
+        let day = stats.days.entry(date).or_default();
+
+        // 여러 줄 항목은 하나로 세고, 체크인 기록은 항목 수에서 제외
+        for entry in parse_log_content(&content, &path_str) {
+            if entry.content.trim().is_empty() {
+                continue;
+            }
+            let Some(checkin) = parser::parse_checkin(&entry.content) else {
+                day.entries += usize::from(!is_generated_entry(&entry.content));
+                completed_on.extend(completed_date(&entry.content, date));
+                continue;
+            };
+            if let Some(valence) = checkin
+                .mood_id
+                .as_deref()
+                .and_then(|id| moods.iter().find(|m| m.id == id))
+                .and_then(|m| m.valence)
+            {
+                day.mood_sum += valence as i64;
+                day.mood_count += 1;
+            }
+            if let Some(energy) = checkin.energy {
+                day.energy_sum += energy as i64;
+                day.energy_count += 1;
+            }
+            if let Some(focus) = checkin.focus {
+                day.focus_sum += focus as i64;
+                day.focus_count += 1;
+            }
+        }
+
+        for done in completed_on {
+            stats.days.entry(done).or_default().todos_done += 1;
+        }
+    }
+
+    for (date, (sessions, _)) in get_pomodoro_stats(log_path)?.daily {
+        stats.days.entry(date).or_default().pomodoros = sessions;
+    }
+
+    Ok(stats)
+}
+
+/// 로그 항목들에서 아직 완료되지 않은 할 일의 시각 알림(`remind:`)을 수집합니다.
+pub fn collect_reminders(entries: &[LogEntry]) -> Vec<Reminder> {
+    entries
+        .iter()
+        .filter_map(|entry| {
+            let text = parser::extract_pending_content(&entry.content)?;
+            let date = file_date(&entry.file_path)?;
+            let created = parser::parse_entry_time(&entry.content, date)?;
+            let due = parser::parse_reminder(&entry.content, created)?;
+            Some(Reminder {
+                entry: entry.clone(),
+                text,
+                due,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::ActivityMetric;
+
+    /// 테스트용 로그 폴더. 테스트가 실패해도 지워집니다.
+    struct TempLogDir(PathBuf);
+
+    impl TempLogDir {
+        fn new(name: &str) -> Self {
+            let dir =
+                std::env::temp_dir().join(format!("sonomemo-{}-{}", name, std::process::id()));
+            let _ = fs::remove_dir_all(&dir);
+            fs::create_dir_all(&dir).unwrap();
+            TempLogDir(dir)
+        }
+
+        fn path(&self) -> &str {
+            self.0.to_str().unwrap()
+        }
+
+        fn write(&self, file: &str, content: &str) {
+            fs::write(self.0.join(file), content).unwrap();
+        }
+    }
+
+    impl Drop for TempLogDir {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.0);
+        }
+    }
+
+    #[test]
+    fn test_activity_stats_counts_parsed_entries() {
+        let dir = TempLogDir::new("activity");
+        dir.write(
+            "2025-01-10.md",
+            "[09:00:00] 여러 줄 메모 #work\n  둘째 줄\n  셋째 줄\n\
+             [09:05:00] Mood: 😊 조음 mood:happy energy:4 focus:3\n\
+             [09:10:00] - [ ] 보고서 쓰기 #work\n\
+             [09:20:00] - [ ] 장보기 #home\n\
+             [09:30:00] Pomodoro: 25m work started #deep\n\
+             [09:30:00] Focus: 보고서 쓰기 #work\n\
+             System: Carryover Checked\n",
+        );
+        dir.write(
+            "2025-01-11.md",
+            "[08:00:00] - [ ] 보고서 쓰기 #work\n\
+             [08:00:00] - [x] 장보기 #home done:2025-01-12T10:00\n\
+             [08:30:00] 회의록 #Work #work/ https://wiki.example.com/page#section\n",
+        );
+
+        let stats = get_activity_stats(dir.path()).unwrap();
+
+        let day = |d| stats.get(&NaiveDate::from_ymd_opt(2025, 1, d).unwrap());
+        let first = day(10).unwrap();
+        assert_eq!(first.value(ActivityMetric::Entries), 3);
+        assert_eq!(first.todos_created, 2);
+        assert_eq!(first.words, 13);
+        assert_eq!(first.tags, 2);
+
+        // 이월된 할 일은 새로 만든 것으로 세지 않고, 완료는 done: 날짜에 셈
+        let second = day(11).unwrap();
+        assert_eq!(second.todos_created, 0);
+        assert_eq!(second.todos_completed, 0);
+        assert_eq!(day(12).unwrap().todos_completed, 1);
+        // 대소문자나 끝 구분자만 다른 태그는 하나로, URL의 `#`은 태그가 아님
+        assert_eq!(second.tags, 2);
+    }
+
+    #[test]
+    fn test_mood_stats_counts_parsed_entries() {
+        let dir = TempLogDir::new("mood");
+        dir.write(
+            "2025-01-10.md",
+            "[09:00:00] 여러 줄 메모\n  둘째 줄\n\n\
+             [09:05:00] Mood: 😊 조음 mood:happy energy:4 focus:3\n\
+             [09:10:00] - [x] 보고서 쓰기 done:10:00\n\
+             [09:20:00] - [x] 장보기 done:2025-01-11T09:00\n\
+             [09:30:00] Timer: 빨래 40m started\n\
+             System: Carryover Checked\n",
+        );
+        let moods = crate::config::MoodList::default().0;
+        let stats = get_mood_stats(dir.path(), &moods).unwrap();
+
+        let day = &stats.days[&NaiveDate::from_ymd_opt(2025, 1, 10).unwrap()];
+        assert_eq!(day.entries, 3);
+        assert_eq!(day.todos_done, 1);
+        // 다음 날 완료한 할 일은 완료한 날에 셈
+        let next_day = &stats.days[&NaiveDate::from_ymd_opt(2025, 1, 11).unwrap()];
+        assert_eq!(next_day.todos_done, 1);
+        assert_eq!(day.mood(), Some(2.0));
+        assert_eq!(day.energy(), Some(4.0));
+    }
+
+    #[test]
+    fn test_swap_entries_moves_continuation_lines() {
+        let dir = TempLogDir::new("swap");
+        let original = "[09:00:00] - [ ] 첫 할 일\n  첫 할 일 메모\n\
+                        [09:10:00] 가운데 메모\n\
+                        [09:20:00] - [ ] 마지막 할 일\n  메모 1\n  메모 2\n";
+        dir.write("2025-01-10.md", original);
+        let path = dir.0.join("2025-01-10.md");
+        let read = || fs::read_to_string(&path).unwrap();
+        let entries = parse_log_content(&read(), &path.to_string_lossy());
+        assert_eq!(entries.len(), 3);
+
+        // 파일 처음과 끝의 여러 줄 항목을 맞바꾸면 이어지는 줄도 함께 이동
+        swap_entries(&entries[0], &entries[2]).unwrap();
+        assert_eq!(
+            read(),
+            "[09:20:00] - [ ] 마지막 할 일\n  메모 1\n  메모 2\n\
+             [09:10:00] 가운데 메모\n\
+             [09:00:00] - [ ] 첫 할 일\n  첫 할 일 메모\n"
+        );
+
+        // 되돌리면 원래대로
+        let swapped = parse_log_content(&read(), &path.to_string_lossy());
+        swap_entries(&swapped[2], &swapped[0]).unwrap();
+        assert_eq!(read(), original);
+
+        // 같은 항목, 다른 파일, 파일 끝을 넘는(오래된) 항목은 아무것도 하지 않음
+        swap_entries(&entries[0], &entries[0]).unwrap();
+        let other_file = LogEntry {
+            file_path: dir.0.join("2025-01-11.md").to_string_lossy().to_string(),
+            ..entries[2].clone()
+        };
+        swap_entries(&entries[0], &other_file).unwrap();
+        let stale = LogEntry {
+            line_number: 10,
+            ..entries[2].clone()
+        };
+        swap_entries(&entries[0], &stale).unwrap();
+        assert_eq!(read(), original);
+    }
+
+    #[test]
+    fn test_recurring_todos_per_task_marker() {
+        let dir = TempLogDir::new("recurring");
+        let log_path = dir.path();
+        // 2025-01-06은 월요일
+        let monday = NaiveDate::from_ymd_opt(2025, 1, 6).unwrap();
+        let todos_on = |date| {
+            fs::read_to_string(get_file_path(log_path, date))
+                .unwrap_or_default()
+                .lines()
+                .filter_map(parser::extract_todo_content)
+                .map(|(_, todo)| todo)
+                .collect::<Vec<_>>()
+        };
+
+        let tasks = [
+            "물 마시기 every:day".to_string(),
+            "주간 회의 every:mon".to_string(),
+        ];
+        insert_recurring_todos(log_path, &tasks, monday).unwrap();
+        assert_eq!(todos_on(monday), vec!["물 마시기", "주간 회의"]);
+        let tuesday = monday + chrono::Duration::days(1);
+        insert_recurring_todos(log_path, &tasks, tuesday).unwrap();
+        assert_eq!(todos_on(tuesday), vec!["물 마시기"]);
+
+        // 지운 할 일은 다시 넣지 않고, 그날 나중에 추가된 설정은 반영
+        let path = get_file_path(log_path, monday);
+        let kept: String = fs::read_to_string(&path)
+            .unwrap()
+            .lines()
+            .filter(|line| parser::extract_todo_content(line).is_none())
+            .map(|line| format!("{}\n", line))
+            .collect();
+        fs::write(&path, kept).unwrap();
+        let tasks = [
+            tasks[0].clone(),
+            tasks[1].clone(),
+            "스트레칭 every:day".to_string(),
+        ];
+        insert_recurring_todos(log_path, &tasks, monday).unwrap();
+        insert_recurring_todos(log_path, &tasks, monday).unwrap();
+        assert_eq!(todos_on(monday), vec!["스트레칭"]);
+    }
+}
@@ -9,7 +9,7 @@ use ratatui::{
 use textwrap::wrap;
 
 use crate::app::App;
-use crate::models::InputMode;
+use crate::models::{FocusPane, InputMode, PomodoroPhase};
 use crate::ui::color_parser::parse_color;
 use ratatui::style::Stylize;
 
@@ -20,8 +20,11 @@ pub mod popups;
 
 use components::parse_log_line;
 use popups::{
-    render_activity_popup, render_mood_popup, render_path_popup, render_pomodoro_popup,
-    render_siren_popup, render_tag_popup, render_todo_popup,
+    render_activity_popup, render_celebration_popup, render_hour_popup, render_interruption_popup,
+    render_mood_popup, render_mood_trend_popup, render_parking_lot_popup, render_path_popup,
+    render_pomodoro_cancel_popup, render_pomodoro_next_popup, render_pomodoro_popup,
+    render_pomodoro_stats_popup, render_reminder_popup, render_siren_popup, render_tag_popup,
+    render_tag_suggestions, render_timers_popup, render_todo_popup,
 };
 
 /// 애플리케이션의 전체 UI를 렌더링하는 메인 함수입니다.
@@ -48,62 +51,178 @@ pub fn ui(f: &mut Frame, app: &mut App) {
     // 상단 로그 뷰
     let list_area_width = top_chunks[0].width.saturating_sub(4) as usize; // 테두리 및 여유 공간
 
-    let list_items: Vec<ListItem> = app
-        .logs
-        .iter()
-        .map(|entry| {
-            // 텍스트 줄바꿈 처리 (멀티라인 엔트리 대응)
-            let mut lines = Vec::new();
-
-            // 사용자가 입력한 엔터(\n)를 기준으로 먼저 나눔
-            for (line_idx, raw_line) in entry.content.lines().enumerate() {
-                let wrapped_lines = wrap(raw_line, list_area_width);
-
-                for (wrap_idx, wline) in wrapped_lines.iter().enumerate() {
-                    // 첫 줄의 첫 조각만 타임스탬프 파싱 시도
-                    if line_idx == 0 && wrap_idx == 0 {
-                        lines.push(parse_log_line(wline, &app.config.theme));
-                    } else {
-                        let display_text = if wrap_idx > 0 {
-                            format!("    {}", wline) // wrap된 줄은 더 깊게 들여쓰기
-                        } else {
-                            format!("{}", wline) // 사용자가 줄바꿈한 줄은 그대로
-                        };
+    let list_items: Vec<ListItem> =
+        app.logs
+            .iter()
+            .map(|entry| {
+                // 텍스트 줄바꿈 처리 (멀티라인 엔트리 대응)
+                let mut lines = Vec::new();
 
-                        lines.push(parse_log_line(&display_text, &app.config.theme));
+                // 사용자가 입력한 엔터(\n)를 기준으로 먼저 나눔
+                for (line_idx, raw_line) in entry.content.lines().enumerate() {
+                    let wrapped_lines = wrap(raw_line, list_area_width);
+
+                    for (wrap_idx, wline) in wrapped_lines.iter().enumerate() {
+                        // 첫 줄의 첫 조각만 타임스탬프 파싱 시도
+                        if line_idx == 0 && wrap_idx == 0 {
+                            lines.push(parse_log_line(wline, &app.config.theme));
+                        } else {
+                            let display_text = if wrap_idx > 0 {
+                                format!("    {}", wline) // wrap된 줄은 더 깊게 들여쓰기
+                            } else {
+                                format!("{}", wline) // 사용자가 줄바꿈한 줄은 그대로
+                            };
+
+                            lines.push(parse_log_line(&display_text, &app.config.theme));
+                        }
                     }
                 }
-            }
-            ListItem::new(Text::from(lines))
-        })
-        .collect();
+                // 기분 기록은 설정된 기분 색으로 표시 (라벨을 바꿔도 식별자로 찾음)
+                let mood_color = parser::parse_mood_id(&entry.content)
+                    .and_then(|id| app.config.moods.find(&id).map(|mood| mood.color.clone()))
+                    .filter(|color| !color.is_empty());
+                match mood_color {
+                    Some(color) => ListItem::new(Text::from(lines))
+                        .style(Style::default().fg(parse_color(&color))),
+                    None => ListItem::new(Text::from(lines)),
+                }
+            })
+            .collect();
 
     let title = if app.is_search_result {
         format!(
             " 🔍 Search Results: {} found (Esc to reset) ",
             app.logs.len()
         )
+    } else if let Some(date) = app.viewing_date {
+        let mode = if app.is_read_only() {
+            "read-only"
+        } else {
+            "editable"
+        };
+        format!(
+            " 📖 Viewing {} ({}) · {} · [Esc] Back to today ",
+            date.format("%Y-%m-%d"),
+            date.format("%a"),
+            mode
+        )
     } else {
         let time = Local::now().format("%Y-%m-%d %H:%M");
-        let pomodoro = if let Some(end_time) = app.pomodoro_end {
-            let now = Local::now();
-            if now < end_time {
-                let remaining = end_time - now;
-                format!(
-                    " [🍅 {:02}:{:02}]",
-                    remaining.num_minutes(),
-                    remaining.num_seconds() % 60
-                )
-            } else {
+        let pomodoro = if let Some(remaining) = app.pomodoro_remaining() {
+            let tags = if app.pomodoro_tags.is_empty() || app.pomodoro_phase != PomodoroPhase::Work
+            {
                 "".to_string()
-            }
+            } else {
+                format!(" {}", app.pomodoro_tags.join(" "))
+            };
+            let interruptions = match app.interruption_counts() {
+                (0, 0) => "".to_string(),
+                (internal, external) => format!(" 💭{} 📞{}", internal, external),
+            };
+            let paused = if app.pomodoro_paused.is_some() {
+                "⏸ "
+            } else {
+                ""
+            };
+            format!(
+                " [{}{} {} #{} {:02}:{:02}{}{}]",
+                paused,
+                app.pomodoro_phase.icon(),
+                app.pomodoro_phase.label(),
+                app.pomodoro_session,
+                remaining.num_minutes(),
+                remaining.num_seconds() % 60,
+                interruptions,
+                tags
+            )
         } else {
             "".to_string()
         };
 
-        format!(" 📝 SONOMEMO - {}{}", time, pomodoro)
+        let focus = match &app.current_focus {
+            Some(todo) => {
+                let short: String = todo.chars().take(30).collect();
+                let ellipsis = if todo.chars().count() > 30 { "…" } else { "" };
+                format!(" [🎯 {}{}]", short, ellipsis)
+            }
+            None => "".to_string(),
+        };
+
+        let timer = match &app.todo_timer {
+            Some((todo, started)) => {
+                let elapsed = Local::now() - *started;
+                let short: String = todo.chars().take(20).collect();
+                let ellipsis = if todo.chars().count() > 20 { "…" } else { "" };
+                format!(
+                    " [⏱ {}{} {:02}:{:02}]",
+                    short,
+                    ellipsis,
+                    elapsed.num_minutes(),
+                    elapsed.num_seconds() % 60
+                )
+            }
+            None => "".to_string(),
+        };
+
+        // 가장 먼저 끝나는 카운트다운(없으면 첫 스톱워치)과 나머지 개수
+        let timers = match app.timers.iter().min_by_key(|t| (t.end.is_none(), t.end)) {
+            Some(first) => {
+                let shown = first.display_duration(Local::now());
+                let more = if app.timers.len() > 1 {
+                    format!(" +{}", app.timers.len() - 1)
+                } else {
+                    "".to_string()
+                };
+                format!(
+                    " [{} {} {:02}:{:02}{}]",
+                    first.icon(),
+                    first.name,
+                    shown.num_minutes(),
+                    shown.num_seconds() % 60,
+                    more
+                )
+            }
+            None => "".to_string(),
+        };
+
+        // 오늘 마지막 체크인 상태 (예: [😊 ⚡4 🧠3])
+        let checkin = match app.latest_checkin() {
+            Some(checkin) => {
+                let mood = checkin
+                    .mood_id
+                    .as_deref()
+                    .and_then(|id| app.config.moods.find(id))
+                    .map(|m| m.emoji.clone())
+                    .unwrap_or_else(|| "?".to_string());
+                let energy = checkin
+                    .energy
+                    .map(|e| format!(" ⚡{}", e))
+                    .unwrap_or_default();
+                let focus = checkin
+                    .focus
+                    .map(|f| format!(" 🧠{}", f))
+                    .unwrap_or_default();
+                format!(" [{}{}{}]", mood, energy, focus)
+            }
+            None => "".to_string(),
+        };
+
+        // 로그를 남긴 연속 일수
+        let streak = match app.log_streak.current {
+            0 => "".to_string(),
+            days => format!(" 🔥{}", days),
+        };
+
+        format!(
+            " 📝 SONOMEMO - {}{}{}{}{}{}{}",
+            time, streak, checkin, pomodoro, focus, timer, timers
+        )
     };
 
+    // Navigate 모드에서 할 일 패널에 포커스가 있으면 그 테두리를 강조
+    let tasks_focused = app.input_mode == InputMode::Navigate && app.focus == FocusPane::Tasks;
+    let focus_color = parse_color(&app.config.theme.border_focus);
+
     // 모드에 따른 메인 테두리 색상 결정
     let main_border_color = match app.input_mode {
         InputMode::Navigate => parse_color(&app.config.theme.border_default),
@@ -129,7 +248,10 @@ pub fn ui(f: &mut Frame, app: &mut App) {
     f.render_stateful_widget(logs_list, top_chunks[0], &mut app.logs_state);
 
     // 오른쪽 할 일 목록 뷰 (오늘의 할 일만 필터링)
-    let todo_area_width = top_chunks[1].width.saturating_sub(2) as usize; // 테두리 제외
+    // 테두리 제외 (포커스 시에는 하이라이트 심볼 폭도 제외)
+    let todo_area_width = top_chunks[1]
+        .width
+        .saturating_sub(if tasks_focused { 4 } else { 2 }) as usize;
 
     let todos: Vec<ListItem> = app
         .logs
@@ -140,7 +262,22 @@ pub fn ui(f: &mut Frame, app: &mut App) {
                 // Reconstruct a displayable todo line (e.g., "- [ ] content")
                 // Or just show the content? The original code showed "- [ ] content".
                 // Let's standardise it to "- [ ] content" for the sidebar.
-                let display_text = format!("- [ ] {}", content);
+                // 사용 시간이 있으면 "예상 대비 실제"를 함께 표시 (예: ⏱ 1h10m/45m)
+                let spent = app.todo_spent_minutes(&entry.content, &content);
+                let estimate = parser::parse_estimate(&content);
+                let over_estimate = matches!((spent, estimate), (Some(s), Some(e)) if s > e);
+                let display_text = match (spent, estimate) {
+                    (Some(s), Some(e)) => format!(
+                        "- [ ] {} ⏱ {}/{}",
+                        content,
+                        parser::format_duration(s),
+                        parser::format_duration(e)
+                    ),
+                    (Some(s), None) => {
+                        format!("- [ ] {} ⏱ {}", content, parser::format_duration(s))
+                    }
+                    _ => format!("- [ ] {}", content),
+                };
 
                 // 줄바꿈 처리
                 let wrapped = wrap(&display_text, todo_area_width);
@@ -153,14 +290,22 @@ pub fn ui(f: &mut Frame, app: &mut App) {
                         lines.push(Line::from(format!("      {}", line)));
                     }
                 }
-                Some(ListItem::new(Text::from(lines)))
+                let mut item = ListItem::new(Text::from(lines));
+                if over_estimate {
+                    item = item.style(Style::default().fg(parse_color(&app.config.theme.todo_wip)));
+                }
+                Some(item)
             } else {
                 None
             }
         })
         .collect();
 
-    let todo_border_color = parse_color(&app.config.theme.border_todo_header);
+    let todo_border_color = if tasks_focused {
+        focus_color
+    } else {
+        parse_color(&app.config.theme.border_todo_header)
+    };
     // 할 일이 없으면 Green(성공?), 있으면 Yellow(진행중?) -> 기본값 유지하되 테마 적용?
     // 기존 로직: if todos.is_empty() { Color::Green } else { Color::Yellow }
     // 여기서는 Configurable하게 만들기 애매하니 일단 todo_border_color를 기본으로 하고 empty일 때만 예외 처리?
@@ -169,11 +314,21 @@ pub fn ui(f: &mut Frame, app: &mut App) {
 
     let todo_block = Block::default()
         .borders(Borders::ALL)
-        .title(" Today's Tasks ")
+        .title(match app.viewing_date {
+            Some(date) => format!(" Tasks of {} ", date.format("%Y-%m-%d")),
+            None => " Today's Tasks ".to_string(),
+        })
         .border_style(Style::default().fg(todo_border_color));
 
-    let todo_list = List::new(todos).block(todo_block);
-    f.render_widget(todo_list, top_chunks[1]);
+    let mut todo_list = List::new(todos).block(todo_block);
+    if tasks_focused {
+        todo_list = todo_list.highlight_symbol("▶ ").highlight_style(
+            Style::default()
+                .bg(highlight_bg)
+                .add_modifier(Modifier::BOLD),
+        );
+    }
+    f.render_stateful_widget(todo_list, top_chunks[1], &mut app.tasks_state);
 
     // 하단 입력창
     let (input_title, border_color) = match app.input_mode {
@@ -221,10 +376,16 @@ pub fn ui(f: &mut Frame, app: &mut App) {
                 .map(|c| unicode_width::UnicodeWidthChar::width(c).unwrap_or(0))
                 .sum();
 
-            f.set_cursor_position((
+            let cursor = (
                 chunks[1].x + visual_col as u16 + 1,
                 chunks[1].y + row as u16 + 1,
-            ));
+            );
+            f.set_cursor_position(cursor);
+
+            // 태그 자동완성 후보는 커서 위치에 붙여서 띄움
+            if app.input_mode == InputMode::Editing && !app.tag_suggestions.is_empty() {
+                render_tag_suggestions(f, app, cursor);
+            }
         }
     }
 
@@ -248,10 +409,42 @@ pub fn ui(f: &mut Frame, app: &mut App) {
         render_activity_popup(f, app);
     }
 
+    if app.show_pomodoro_stats_popup {
+        render_pomodoro_stats_popup(f, app);
+    }
+
+    if app.show_mood_trend_popup {
+        render_mood_trend_popup(f, app);
+    }
+
+    if app.show_hour_popup {
+        render_hour_popup(f, app);
+    }
+
     if app.show_pomodoro_popup {
         render_pomodoro_popup(f, app);
     }
 
+    if app.pomodoro_next.is_some() {
+        render_pomodoro_next_popup(f, app);
+    }
+
+    if app.show_pomodoro_cancel_popup {
+        render_pomodoro_cancel_popup(f, app);
+    }
+
+    if app.show_parking_lot_popup {
+        render_parking_lot_popup(f, app);
+    }
+
+    if app.show_interruption_popup {
+        render_interruption_popup(f, app);
+    }
+
+    if app.show_timers_popup {
+        render_timers_popup(f, app);
+    }
+
     if app.show_mood_popup {
         render_mood_popup(f, app);
     }
@@ -264,20 +457,29 @@ pub fn ui(f: &mut Frame, app: &mut App) {
         render_tag_popup(f, app);
     }
 
-    if app.pomodoro_alert_expiry.is_some() {
-        render_siren_popup(f);
-    }
-
     if app.show_path_popup {
         render_path_popup(f, app);
     }
 
+    if app.active_reminder.is_some() {
+        render_reminder_popup(f, app);
+    }
+
+    if app.celebration.is_some() {
+        render_celebration_popup(f, app);
+    }
+
+    if app.show_siren_popup {
+        render_siren_popup(f, app);
+    }
+
     // Render notification overlay
     if let Some((message, _)) = &app.notification {
         use ratatui::widgets::Clear;
 
         let area = f.area();
-        let width = 30;
+        // 긴 알림(주기 알림 등)은 문구 길이에 맞춰 넓힘
+        let width = (Line::from(message.as_str()).width() as u16 + 4).clamp(30, area.width);
         let height = 3;
         let x = (area.width.saturating_sub(width)) / 2;
         let y = area.height.saturating_sub(height + 2); // Slightly above bottom
@@ -1,9 +1,12 @@
-use crate::config::Theme;
+use crate::config::{MoodConfig, Theme};
+use crate::models::{Checkin, Interruption, PomodoroEvent, PomodoroPhase, Recurrence};
 use crate::ui::color_parser::parse_color;
+use chrono::{NaiveDate, NaiveDateTime, NaiveTime, Timelike};
 use ratatui::{
     style::{Color, Modifier, Style},
     text::{Line, Span},
 };
+use std::collections::HashSet;
 
 /// 로그 라인의 의미적 구성 요소들을 정의하는 열거형입니다.
 #[derive(Debug, PartialEq, Clone)]
@@ -11,8 +14,17 @@ pub enum LogToken<'a> {
     Timestamp(&'a str),     // [HH:MM:SS]
     Todo { checked: bool }, // - [ ] or - [x]
     Mood,                   // Mood:
+    MoodId(&'a str),        // mood:happy, energy:4, focus:3 (체크인 값)
+    Focus,                  // Focus:
+    Pomodoro,               // Pomodoro:
+    Interruption,           // Interruption:
+    Timer,                  // Timer:
     Tag(&'a str),           // #tag
     Url(&'a str),           // http://...
+    Done(&'a str),          // done:HH:MM (완료 시각)
+    Reminder(&'a str),      // remind:15:30, remind:+45m
+    Estimate(&'a str),      // ~45m (예상 소요 시간)
+    Spent(&'a str),         // spent:1h10m (실제 사용 시간)
     Text(&'a str),          // Normal text
     Whitespace(&'a str),    // Space or other whitespace
 }
@@ -63,11 +75,16 @@ pub fn tokenize(text: &str) -> Vec<LogToken<'_>> {
     // 3. Extract Todo Status (Always after timestamp)
     // Regex: hyphen, optional whitespace, open bracket, (optional whitespace OR x/X), closing bracket
     // 3. Extract Todo Status (Always after timestamp)
+    let mut is_todo = false;
     if let Some((checked, len)) = try_parse_todo(current_text) {
         tokens.push(LogToken::Todo { checked });
         current_text = &current_text[len..];
+        is_todo = true;
     }
 
+    // 기분 기록이면 `mood:<id>` 식별자를 별도 토큰으로 분리
+    let mut is_mood = false;
+
     // 4. Tokenize Remaining Content (Words)
     static URL_REGEX: std::sync::OnceLock<regex::Regex> = std::sync::OnceLock::new();
     let url_regex = URL_REGEX.get_or_init(|| {
@@ -101,7 +118,36 @@ pub fn tokenize(text: &str) -> Vec<LogToken<'_>> {
         if word.starts_with('#') {
             tokens.push(LogToken::Tag(word));
         } else if *word == "Mood:" {
+            is_mood = true;
             tokens.push(LogToken::Mood);
+        } else if is_mood
+            && [MOOD_ID_PREFIX, ENERGY_PREFIX, FOCUS_RATING_PREFIX]
+                .iter()
+                .any(|prefix| word.starts_with(prefix))
+        {
+            tokens.push(LogToken::MoodId(word));
+        } else if *word == "Focus:" {
+            tokens.push(LogToken::Focus);
+        } else if *word == "Pomodoro:" {
+            tokens.push(LogToken::Pomodoro);
+        } else if *word == "Interruption:" {
+            tokens.push(LogToken::Interruption);
+        } else if *word == "Timer:" {
+            tokens.push(LogToken::Timer);
+        } else if is_todo && is_done_stamp(word) {
+            tokens.push(LogToken::Done(word));
+        } else if is_todo && (word.starts_with(REMIND_PREFIX) || word.starts_with(REMINDED_PREFIX))
+        {
+            tokens.push(LogToken::Reminder(word));
+        } else if is_todo && word.starts_with(SPENT_PREFIX) {
+            tokens.push(LogToken::Spent(word));
+        } else if is_todo
+            && word
+                .strip_prefix(ESTIMATE_PREFIX)
+                .and_then(parse_duration)
+                .is_some()
+        {
+            tokens.push(LogToken::Estimate(word));
         } else if let Some(mat) = url_regex.find(word) {
             let start = mat.start();
             let end = mat.end();
@@ -154,13 +200,85 @@ pub fn render_tokens<'a>(tokens: Vec<LogToken<'a>>, theme: &Theme) -> Line<'stat
                     Style::default().fg(color).add_modifier(Modifier::ITALIC),
                 ));
             }
+            LogToken::MoodId(id) => {
+                let color = parse_color(&theme.mood);
+                spans.push(Span::styled(
+                    id.to_string(),
+                    Style::default().fg(color).add_modifier(Modifier::DIM),
+                ));
+            }
+            LogToken::Focus => {
+                let color = parse_color(&theme.focus);
+                spans.push(Span::styled(
+                    "🎯 Focus:",
+                    Style::default().fg(color).add_modifier(Modifier::BOLD),
+                ));
+            }
+            LogToken::Pomodoro => {
+                let color = parse_color(&theme.pomodoro);
+                spans.push(Span::styled(
+                    "🍅 Pomodoro:",
+                    Style::default().fg(color).add_modifier(Modifier::BOLD),
+                ));
+            }
+            LogToken::Interruption => {
+                let color = parse_color(&theme.pomodoro);
+                spans.push(Span::styled(
+                    "⚡ Interruption:",
+                    Style::default().fg(color).add_modifier(Modifier::ITALIC),
+                ));
+            }
+            LogToken::Timer => {
+                let color = parse_color(&theme.focus);
+                spans.push(Span::styled(
+                    "⏲ Timer:",
+                    Style::default().fg(color).add_modifier(Modifier::ITALIC),
+                ));
+            }
             LogToken::Tag(tag) => {
                 let color = parse_color(&theme.tag);
+                // 계층 태그는 상위 경로를 흐리게, 마지막 이름을 강조
+                let split = tag.rfind(TAG_SEPARATOR).map_or(0, |index| index + 1);
+                if split > 0 {
+                    spans.push(Span::styled(
+                        tag[..split].to_string(),
+                        Style::default().fg(color).add_modifier(Modifier::DIM),
+                    ));
+                }
                 spans.push(Span::styled(
-                    tag.to_string(),
+                    tag[split..].to_string(),
                     Style::default().fg(color).add_modifier(Modifier::BOLD),
                 ));
             }
+            LogToken::Done(stamp) => {
+                let color = parse_color(&theme.todo_done);
+                spans.push(Span::styled(
+                    stamp.to_string(),
+                    Style::default().fg(color).add_modifier(Modifier::DIM),
+                ));
+            }
+            LogToken::Estimate(estimate) => {
+                let color = parse_color(&theme.focus);
+                spans.push(Span::styled(
+                    estimate.to_string(),
+                    Style::default().fg(color).add_modifier(Modifier::ITALIC),
+                ));
+            }
+            LogToken::Spent(spent) => {
+                let color = parse_color(&theme.timestamp);
+                spans.push(Span::styled(
+                    format!("⏱ {}", spent),
+                    Style::default().fg(color).add_modifier(Modifier::DIM),
+                ));
+            }
+            LogToken::Reminder(reminder) => {
+                let color = parse_color(&theme.timestamp);
+                let mut style = Style::default().fg(color);
+                if reminder.starts_with(REMINDED_PREFIX) {
+                    style = style.add_modifier(Modifier::DIM | Modifier::CROSSED_OUT);
+                }
+                spans.push(Span::styled(format!("🔔{}", reminder), style));
+            }
             LogToken::Url(url) => {
                 spans.push(Span::styled(
                     url.to_string(),
@@ -207,9 +325,532 @@ pub fn toggle_checkbox(text: &str) -> String {
     text.to_string()
 }
 
-/// 완료되지 않은(체크되지 않은) 할 일 항목의 내용을 추출합니다.
-/// 할 일이 아니거나 체크된 상태라면 `None`을 반환합니다.
-pub fn extract_pending_content(text: &str) -> Option<String> {
+/// 기분 기록의 접두어입니다.
+pub const MOOD_PREFIX: &str = "Mood: ";
+/// 기분 기록에 붙는 안정적인 식별자 주석(`mood:happy`)의 접두어입니다.
+const MOOD_ID_PREFIX: &str = "mood:";
+/// 체크인의 에너지(1~5) 주석 접두어입니다.
+const ENERGY_PREFIX: &str = "energy:";
+/// 체크인의 집중도(1~5) 주석 접두어입니다.
+const FOCUS_RATING_PREFIX: &str = "focus:";
+
+/// 예전(식별자 없이 라벨만 저장하던) 기분 기록의 라벨과 식별자 대응표입니다.
+const LEGACY_MOODS: [(&str, &str); 5] = [
+    ("😊 조음", "happy"),
+    ("😐 걍그럼", "neutral"),
+    ("😫 구림", "stressed"),
+    ("🧐 집중", "focused"),
+    ("😴 피곤", "tired"),
+];
+
+/// 기분 체크인을 로그에 기록할 문자열로 변환합니다. (`Mood: 😊 조음 mood:happy energy:4 focus:3`)
+pub fn format_mood_entry(mood: &MoodConfig, energy: Option<u8>, focus: Option<u8>) -> String {
+    let mut entry = format!(
+        "{}{} {} {}{}",
+        MOOD_PREFIX, mood.emoji, mood.label, MOOD_ID_PREFIX, mood.id
+    );
+    if let Some(energy) = energy {
+        entry.push_str(&format!(" {}{}", ENERGY_PREFIX, energy));
+    }
+    if let Some(focus) = focus {
+        entry.push_str(&format!(" {}{}", FOCUS_RATING_PREFIX, focus));
+    }
+    entry
+}
+
+/// `Mood: ...` 로그라면 기분 식별자와 에너지/집중도 점수를 읽습니다.
+pub fn parse_checkin(text: &str) -> Option<Checkin> {
+    let body = entry_body(text).strip_prefix(MOOD_PREFIX)?;
+    let rating = |prefix: &str| {
+        body.split_whitespace()
+            .find_map(|w| w.strip_prefix(prefix)?.parse::<u8>().ok())
+            .filter(|v| (1..=5).contains(v))
+    };
+    Some(Checkin {
+        mood_id: parse_mood_id(text),
+        energy: rating(ENERGY_PREFIX),
+        focus: rating(FOCUS_RATING_PREFIX),
+    })
+}
+
+/// `Mood: ...` 로그라면 기분 식별자를 반환합니다.
+/// 식별자가 없는 예전 기록은 기본 라벨로 대응시킵니다.
+pub fn parse_mood_id(text: &str) -> Option<String> {
+    let body = entry_body(text).strip_prefix(MOOD_PREFIX)?;
+    if let Some(id) = body
+        .split_whitespace()
+        .find_map(|w| w.strip_prefix(MOOD_ID_PREFIX))
+    {
+        return Some(id.to_string());
+    }
+    LEGACY_MOODS
+        .iter()
+        .find(|(label, _)| body.trim() == *label)
+        .map(|(_, id)| id.to_string())
+}
+
+/// 현재 집중 중인 할 일을 기록하는 로그의 접두어입니다.
+pub const FOCUS_PREFIX: &str = "Focus: ";
+/// 집중을 해제했음을 나타내는 `Focus:` 로그의 내용입니다. (`Focus: (none)`)
+const FOCUS_CLEARED: &str = "(none)";
+
+/// `Focus:` 로그가 나타내는 집중 상태의 변화입니다.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FocusEntry<'a> {
+    /// 이 할 일에 집중 시작
+    Set(&'a str),
+    /// 집중 해제
+    Cleared,
+}
+
+/// 로그 라인 앞의 타임스탬프(`[...]`)와 공백을 제외한 본문을 반환합니다.
+pub fn entry_body(text: &str) -> &str {
+    let text = text.trim_start();
+    if text.starts_with('[')
+        && let Some(end_idx) = text.find(']')
+    {
+        return text[end_idx + 1..].trim_start();
+    }
+    text
+}
+
+/// `Focus: ...` 로그라면 집중 대상 할 일 또는 해제 여부를 반환합니다.
+pub fn parse_focus_entry(text: &str) -> Option<FocusEntry<'_>> {
+    let focus = entry_body(text).strip_prefix(FOCUS_PREFIX)?.trim();
+    Some(if focus == FOCUS_CLEARED {
+        FocusEntry::Cleared
+    } else {
+        FocusEntry::Set(focus)
+    })
+}
+
+/// 집중 시작(`Some(할 일)`) 또는 해제(`None`) 로그를 만듭니다.
+pub fn format_focus_entry(todo: Option<&str>) -> String {
+    format!("{}{}", FOCUS_PREFIX, todo.unwrap_or(FOCUS_CLEARED))
+}
+
+/// `22:00-08:00` 형식의 시간대를 해석합니다.
+pub fn parse_time_range(text: &str) -> Option<(NaiveTime, NaiveTime)> {
+    let (start, end) = text.trim().split_once('-')?;
+    let start = NaiveTime::parse_from_str(start.trim(), "%H:%M").ok()?;
+    let end = NaiveTime::parse_from_str(end.trim(), "%H:%M").ok()?;
+    Some((start, end))
+}
+
+/// `time`이 주어진 시간대들 중 하나에 속하는지 확인합니다. 자정을 넘는 시간대(`22:00-08:00`)도 지원합니다.
+pub fn in_time_ranges(ranges: &[String], time: NaiveTime) -> bool {
+    ranges
+        .iter()
+        .filter_map(|range| parse_time_range(range))
+        .any(|(start, end)| {
+            if start <= end {
+                start <= time && time < end
+            } else {
+                time >= start || time < end
+            }
+        })
+}
+
+/// 뽀모도로 이벤트를 기록하는 로그의 접두어입니다.
+pub const POMODORO_PREFIX: &str = "Pomodoro: ";
+
+/// 뽀모도로 이벤트를 로그에 기록할 문자열로 변환합니다.
+pub fn format_pomodoro_event(event: &PomodoroEvent) -> String {
+    let body = match event {
+        PomodoroEvent::Started {
+            phase,
+            minutes,
+            tags,
+        } => {
+            let mut body = match phase.log_name() {
+                Some(name) => format!("{}m {} started", minutes, name),
+                None => format!("{}m started", minutes),
+            };
+            for tag in tags {
+                body.push(' ');
+                body.push_str(tag);
+            }
+            body
+        }
+        PomodoroEvent::Completed {
+            internal: 0,
+            external: 0,
+        } => "completed".to_string(),
+        PomodoroEvent::Completed { internal, external } => {
+            format!("completed int:{} ext:{}", internal, external)
+        }
+        PomodoroEvent::Stopped => "stopped".to_string(),
+        PomodoroEvent::Paused => "paused".to_string(),
+        PomodoroEvent::Resumed => "resumed".to_string(),
+        PomodoroEvent::Extended { minutes } => format!("extended +{}m", minutes),
+    };
+    format!("{}{}", POMODORO_PREFIX, body)
+}
+
+/// 이름 붙은 타이머/스톱워치 기록의 접두어입니다.
+pub const TIMER_PREFIX: &str = "Timer: ";
+
+/// 타이머 입력을 이름과 카운트다운 시간(분)으로 나눕니다.
+/// 마지막 단어가 기간이면 카운트다운(`빨래 40m`, `standup in 10m`), 아니면 스톱워치(`통화`)입니다.
+pub fn parse_timer_spec(input: &str) -> Option<(String, Option<i64>)> {
+    let mut words: Vec<&str> = input.split_whitespace().collect();
+    let minutes = words.last().and_then(|w| parse_duration(w));
+    if minutes.is_some() {
+        words.pop();
+        if words.last() == Some(&"in") {
+            words.pop();
+        }
+    }
+    if words.is_empty() && minutes.is_none() {
+        return None;
+    }
+    let name = if words.is_empty() {
+        "timer".to_string()
+    } else {
+        words.join(" ")
+    };
+    Some((name, minutes))
+}
+
+/// 뽀모도로 도중의 방해를 기록하는 로그의 접두어입니다.
+pub const INTERRUPTION_PREFIX: &str = "Interruption: ";
+
+/// 방해 기록을 로그에 남길 문자열로 변환합니다. (`Interruption: external 팀장님 호출`)
+pub fn format_interruption(interruption: &Interruption) -> String {
+    format!(
+        "{}{} {}",
+        INTERRUPTION_PREFIX,
+        interruption.kind(),
+        interruption.text
+    )
+}
+
+/// `Interruption: ...` 로그라면 방해 기록으로 해석합니다.
+pub fn parse_interruption_entry(text: &str) -> Option<Interruption> {
+    let body = entry_body(text).strip_prefix(INTERRUPTION_PREFIX)?;
+    let (kind, rest) = body.split_once(' ').unwrap_or((body, ""));
+    let external = match kind {
+        "internal" => false,
+        "external" => true,
+        _ => return None,
+    };
+    Some(Interruption {
+        external,
+        text: rest.trim().to_string(),
+    })
+}
+
+/// `Pomodoro: ...` 로그라면 뽀모도로 이벤트로 해석합니다.
+pub fn parse_pomodoro_entry(text: &str) -> Option<PomodoroEvent> {
+    let body = entry_body(text).strip_prefix(POMODORO_PREFIX)?;
+    let words: Vec<&str> = body.split_whitespace().collect();
+
+    match words.as_slice() {
+        ["completed", rest @ ..] => {
+            let count = |prefix: &str| {
+                rest.iter()
+                    .find_map(|w| w.strip_prefix(prefix)?.parse().ok())
+                    .unwrap_or(0)
+            };
+            Some(PomodoroEvent::Completed {
+                internal: count("int:"),
+                external: count("ext:"),
+            })
+        }
+        ["stopped", ..] => Some(PomodoroEvent::Stopped),
+        ["paused", ..] => Some(PomodoroEvent::Paused),
+        ["resumed", ..] => Some(PomodoroEvent::Resumed),
+        ["extended", duration, ..] => Some(PomodoroEvent::Extended {
+            minutes: parse_duration(duration.strip_prefix('+')?)?,
+        }),
+        [duration, rest @ ..] => {
+            let minutes = parse_duration(duration)?;
+            let (phase, rest) = match rest {
+                ["started", rest @ ..] => (PomodoroPhase::Work, rest),
+                ["short-break", "started", rest @ ..] => (PomodoroPhase::ShortBreak, rest),
+                ["long-break", "started", rest @ ..] => (PomodoroPhase::LongBreak, rest),
+                _ => return None,
+            };
+            Some(PomodoroEvent::Started {
+                phase,
+                minutes,
+                tags: rest
+                    .iter()
+                    .filter(|w| w.starts_with('#'))
+                    .map(|w| w.to_string())
+                    .collect(),
+            })
+        }
+        _ => None,
+    }
+}
+
+/// 완료 시각 주석(`done:HH:MM`)의 접두어입니다.
+const DONE_PREFIX: &str = "done:";
+
+/// 할 일 완료 시각 주석을 만듭니다.
+/// 로그가 작성된 날짜(`entry_date`)에 완료했다면 `done:HH:MM`,
+/// 다른 날 완료했다면 `done:YYYY-MM-DDTHH:MM` 형식입니다.
+pub fn format_done_stamp(done_at: NaiveDateTime, entry_date: NaiveDate) -> String {
+    if done_at.date() == entry_date {
+        format!("{}{}", DONE_PREFIX, done_at.format("%H:%M"))
+    } else {
+        format!("{}{}", DONE_PREFIX, done_at.format("%Y-%m-%dT%H:%M"))
+    }
+}
+
+/// 체크박스를 토글하면서 완료 시각 주석을 함께 관리합니다.
+/// 완료로 바뀌면 `done_stamp`를 줄 끝에 붙이고, 미완료로 돌아가면 기존 주석을 제거합니다.
+pub fn toggle_checkbox_with_stamp(text: &str, done_stamp: &str) -> String {
+    let toggled = toggle_checkbox(&strip_done_stamp(text));
+    match try_parse_todo(&toggled) {
+        Some((true, _)) => format!("{} {}", toggled.trim_end(), done_stamp),
+        _ => toggled,
+    }
+}
+
+/// 완료된 할 일(`- [x]`) 라인 끝의 완료 시각 주석(`done:HH:MM`, `done:YYYY-MM-DDTHH:MM`)을 제거합니다.
+/// 다른 라인이나, 시각 형식이 아닌 `done:` 글(`done:prod`)은 그대로 둡니다.
+pub fn strip_done_stamp(text: &str) -> String {
+    if !matches!(extract_todo_content(text), Some((true, _))) {
+        return text.to_string();
+    }
+    let trimmed = text.trim_end();
+    let start = trimmed.rfind(char::is_whitespace).map_or(0, |i| i + 1);
+    if !is_done_stamp(&trimmed[start..]) {
+        return text.to_string();
+    }
+    trimmed[..start].trim_end().to_string()
+}
+
+/// 완료 시각 주석의 값(`HH:MM` 또는 `YYYY-MM-DDTHH:MM`)을 읽습니다.
+fn parse_done_value(value: &str, entry_date: NaiveDate) -> Option<NaiveDateTime> {
+    NaiveDateTime::parse_from_str(value, "%Y-%m-%dT%H:%M")
+        .ok()
+        .or_else(|| {
+            NaiveTime::parse_from_str(value, "%H:%M")
+                .ok()
+                .map(|t| entry_date.and_time(t))
+        })
+}
+
+/// 단어가 완료 시각 주석(`done:HH:MM`, `done:YYYY-MM-DDTHH:MM`)인지 확인합니다.
+fn is_done_stamp(word: &str) -> bool {
+    word.strip_prefix(DONE_PREFIX)
+        .and_then(|value| parse_done_value(value, NaiveDate::default()))
+        .is_some()
+}
+
+/// 라인의 완료 시각 주석을 읽어옵니다. 시각만 적혀 있다면 `entry_date` 날짜로 해석합니다.
+/// 주석이 여러 개면 마지막 것을 씁니다.
+pub fn parse_done_stamp(text: &str, entry_date: NaiveDate) -> Option<NaiveDateTime> {
+    text.split_whitespace()
+        .rev()
+        .filter_map(|w| w.strip_prefix(DONE_PREFIX))
+        .find_map(|value| parse_done_value(value, entry_date))
+}
+
+/// 로그 앞의 `[HH:MM:SS]` 타임스탬프에서 시(0~23)를 읽습니다.
+pub fn parse_entry_hour(text: &str) -> Option<u32> {
+    parse_entry_time(text, NaiveDate::default()).map(|t| t.hour())
+}
+
+/// 커서(`col`번째 글자) 뒤로 공백 전까지 이어지는 부분을 반환합니다. (`#wo|rk`의 `rk`)
+pub fn word_suffix_at(line: &str, col: usize) -> &str {
+    let start = line
+        .char_indices()
+        .nth(col)
+        .map_or(line.len(), |(index, _)| index);
+    let rest = &line[start..];
+    &rest[..rest.find(char::is_whitespace).unwrap_or(rest.len())]
+}
+
+/// 커서(`col`번째 글자) 바로 앞에서 입력 중인 태그(`#...`)를 반환합니다.
+pub fn tag_prefix_at(line: &str, col: usize) -> Option<&str> {
+    let end = line
+        .char_indices()
+        .nth(col)
+        .map_or(line.len(), |(index, _)| index);
+    let before = &line[..end];
+    let start = before.rfind(char::is_whitespace).map_or(0, |index| {
+        index + before[index..].chars().next().map_or(1, char::len_utf8)
+    });
+    let word = &before[start..];
+    word.starts_with('#').then_some(word)
+}
+
+/// 입력 중인 태그에 맞는 기존 태그를 순위대로 최대 `limit`개 고릅니다.
+/// 대소문자를 무시하고 앞부분이 같은 태그를 먼저, 그다음 중간에 포함하는 태그를 보여줍니다.
+/// 대소문자만 다른 태그는 순위가 높은 표기 하나만 보여주고, 이미 입력한 태그는 제외합니다.
+pub fn complete_tag(tags: &[(String, usize)], prefix: &str, limit: usize) -> Vec<String> {
+    let needle = prefix.trim_start_matches('#').to_lowercase();
+    let key = |tag: &str| normalize_tag(tag).map(|tag| tag.to_lowercase());
+    let typed = key(prefix);
+    let mut seen = HashSet::new();
+    let unique: Vec<&String> = tags
+        .iter()
+        .map(|(tag, _)| tag)
+        .filter(|tag| {
+            let key = key(tag);
+            key != typed && seen.insert(key)
+        })
+        .collect();
+    let candidates = || unique.iter().copied();
+    let starts = candidates().filter(|tag| {
+        tag.trim_start_matches('#')
+            .to_lowercase()
+            .starts_with(&needle)
+    });
+    let contains = candidates().filter(|tag| {
+        let lower = tag.trim_start_matches('#').to_lowercase();
+        !lower.starts_with(&needle) && lower.contains(&needle)
+    });
+    starts.chain(contains).take(limit).cloned().collect()
+}
+
+/// 계층 태그(`#work/sonomemo/bugs`)의 구분자입니다.
+pub const TAG_SEPARATOR: char = '/';
+
+/// 태그를 `#a/b/c` 꼴로 정리합니다. (`#` 보충, 빈 단계와 끝의 `/` 제거)
+/// 이름이 없는 태그면 `None`입니다.
+pub fn normalize_tag(tag: &str) -> Option<String> {
+    let segments: Vec<&str> = tag
+        .trim()
+        .trim_start_matches('#')
+        .split(TAG_SEPARATOR)
+        .filter(|segment| !segment.is_empty())
+        .collect();
+    (!segments.is_empty()).then(|| format!("#{}", segments.join("/")))
+}
+
+/// `tag`가 `query` 태그 자신이거나 그 하위 태그인지 확인합니다. (대소문자 무시)
+/// 예: `#work`로 찾으면 `#work/sonomemo/bugs`도 해당되지만 `#workout`은 아닙니다.
+pub fn tag_matches(tag: &str, query: &str) -> bool {
+    let (Some(tag), Some(query)) = (normalize_tag(tag), normalize_tag(query)) else {
+        return false;
+    };
+    let (tag, query) = (tag.to_lowercase(), query.to_lowercase());
+    tag == query
+        || tag
+            .strip_prefix(query.as_str())
+            .is_some_and(|rest| rest.starts_with(TAG_SEPARATOR))
+}
+
+/// 검색어가 태그 하나(`#...`)뿐인지 확인합니다.
+pub fn is_tag_query(query: &str) -> bool {
+    let query = query.trim();
+    query.starts_with('#') && !query.contains(char::is_whitespace) && normalize_tag(query).is_some()
+}
+
+/// 로그에 태그(또는 그 하위 태그)가 붙어 있는지 확인합니다. (`#`은 생략 가능, 대소문자 무시)
+pub fn has_tag(text: &str, tag: &str) -> bool {
+    text.lines().any(|line| {
+        tokenize(line)
+            .iter()
+            .any(|token| matches!(token, LogToken::Tag(t) if tag_matches(t, tag)))
+    })
+}
+
+/// 라인 앞의 타임스탬프(`[HH:MM:SS]`)를 `date` 날짜의 시각으로 읽어옵니다.
+pub fn parse_entry_time(text: &str, date: NaiveDate) -> Option<NaiveDateTime> {
+    let rest = text.strip_prefix('[')?;
+    let end = rest.find(']')?;
+    NaiveTime::parse_from_str(&rest[..end], "%H:%M:%S")
+        .or_else(|_| NaiveTime::parse_from_str(&rest[..end], "%H:%M"))
+        .ok()
+        .map(|t| date.and_time(t))
+}
+
+/// 알림 주석(`remind:15:30`, `remind:+45m`)의 접두어입니다.
+const REMIND_PREFIX: &str = "remind:";
+/// 이미 확인(dismiss)한 알림 주석의 접두어입니다.
+const REMINDED_PREFIX: &str = "reminded:";
+
+/// 라인의 알림 주석을 읽어 알림 시각을 계산합니다.
+/// `remind:HH:MM`은 `entry_time`과 같은 날의 해당 시각, `remind:+45m`은 `entry_time` 기준 상대 시각입니다.
+pub fn parse_reminder(text: &str, entry_time: NaiveDateTime) -> Option<NaiveDateTime> {
+    let spec = text
+        .split_whitespace()
+        .find_map(|w| w.strip_prefix(REMIND_PREFIX))?;
+
+    if let Some(relative) = spec.strip_prefix('+') {
+        let minutes = parse_duration(relative)?;
+        return Some(entry_time + chrono::Duration::minutes(minutes));
+    }
+
+    NaiveTime::parse_from_str(spec, "%H:%M")
+        .ok()
+        .map(|t| entry_time.date().and_time(t))
+}
+
+/// 알림을 확인 처리합니다. `remind:` 주석을 `reminded:`로 바꿔 다시 울리지 않게 합니다.
+pub fn mark_reminder_dismissed(text: &str) -> String {
+    static REMIND_REGEX: std::sync::OnceLock<regex::Regex> = std::sync::OnceLock::new();
+    let remind_regex = REMIND_REGEX.get_or_init(|| regex::Regex::new(r"\bremind:").unwrap());
+    remind_regex.replace(text, REMINDED_PREFIX).to_string()
+}
+
+/// `45m`, `2h`, `1h30m` 같은 짧은 기간 표기를 분 단위로 변환합니다.
+pub fn parse_duration(text: &str) -> Option<i64> {
+    static DURATION_REGEX: std::sync::OnceLock<regex::Regex> = std::sync::OnceLock::new();
+    let duration_regex =
+        DURATION_REGEX.get_or_init(|| regex::Regex::new(r"^(?:(\d+)h)?(?:(\d+)m)?$").unwrap());
+
+    let caps = duration_regex.captures(text)?;
+    let hours = caps.get(1).and_then(|m| m.as_str().parse::<i64>().ok());
+    let minutes = caps.get(2).and_then(|m| m.as_str().parse::<i64>().ok());
+    if hours.is_none() && minutes.is_none() {
+        return None;
+    }
+    Some(hours.unwrap_or(0) * 60 + minutes.unwrap_or(0))
+}
+
+/// 할 일의 예상 소요 시간 표기(`~45m`)의 접두어입니다.
+const ESTIMATE_PREFIX: &str = "~";
+/// 할 일에 누적된 실제 사용 시간 주석(`spent:1h10m`)의 접두어입니다.
+pub const SPENT_PREFIX: &str = "spent:";
+
+/// 할 일 라인의 예상 소요 시간(`~45m`)을 분 단위로 읽습니다.
+pub fn parse_estimate(text: &str) -> Option<i64> {
+    text.split_whitespace()
+        .find_map(|w| parse_duration(w.strip_prefix(ESTIMATE_PREFIX)?))
+}
+
+/// 할 일 라인에 누적된 실제 사용 시간(`spent:1h10m`)을 분 단위로 읽습니다.
+pub fn parse_spent(text: &str) -> Option<i64> {
+    text.split_whitespace()
+        .find_map(|w| parse_duration(w.strip_prefix(SPENT_PREFIX)?))
+}
+
+/// 할 일 라인의 `spent:` 주석에 `minutes`를 더합니다. 주석이 없으면 줄 끝에 새로 붙입니다.
+pub fn add_spent(text: &str, minutes: i64) -> String {
+    let total = format!(
+        "{}{}",
+        SPENT_PREFIX,
+        format_duration(parse_spent(text).unwrap_or(0) + minutes)
+    );
+    match text
+        .split_whitespace()
+        .find(|w| w.starts_with(SPENT_PREFIX))
+    {
+        Some(old) => text.replacen(old, &total, 1),
+        None => format!("{} {}", text.trim_end(), total),
+    }
+}
+
+/// 분 단위 시간을 `1h10m`, `45m` 같은 짧은 형식으로 표시합니다.
+pub fn format_duration(minutes: i64) -> String {
+    let (hours, mins) = (minutes / 60, minutes % 60);
+    match (hours, mins) {
+        (0, m) => format!("{}m", m),
+        (h, 0) => format!("{}h", h),
+        (h, m) => format!("{}h{}m", h, m),
+    }
+}
+
+/// 할 일 항목의 체크 여부와 내용을 추출합니다.
+/// 할 일이 아니라면 `None`을 반환합니다.
+pub fn extract_todo_content(text: &str) -> Option<(bool, String)> {
     let tokens = tokenize(text);
     let mut is_todo = false;
     let mut is_checked = false;
@@ -221,29 +862,106 @@ pub fn extract_pending_content(text: &str) -> Option<String> {
                 is_todo = true;
                 is_checked = checked;
             }
-            LogToken::Text(t) | LogToken::Tag(t) | LogToken::Url(t) | LogToken::Whitespace(t) => {
-                // Only collect content AFTER the todo token
-                if is_todo {
-                    content.push_str(t);
-                }
+            // Only collect content AFTER the todo token
+            LogToken::Text(t)
+            | LogToken::Tag(t)
+            | LogToken::Url(t)
+            | LogToken::Estimate(t)
+            | LogToken::Whitespace(t)
+                if is_todo =>
+            {
+                content.push_str(t);
             }
-            LogToken::Mood => {
-                // Mood can be part of content?
-                if is_todo {
-                    content.push_str("Mood:");
-                }
+            // Mood can be part of content?
+            LogToken::Mood if is_todo => {
+                content.push_str("Mood:");
+            }
+            LogToken::Focus if is_todo => {
+                content.push_str("Focus:");
+            }
+            LogToken::Pomodoro if is_todo => {
+                content.push_str("Pomodoro:");
+            }
+            LogToken::Interruption if is_todo => {
+                content.push_str("Interruption:");
+            }
+            LogToken::Timer if is_todo => {
+                content.push_str("Timer:");
             }
             _ => {}
         }
     }
 
-    if is_todo && !is_checked {
-        Some(content.trim().to_string())
+    if is_todo {
+        Some((is_checked, content.trim().to_string()))
     } else {
         None
     }
 }
 
+/// 완료되지 않은(체크되지 않은) 할 일 항목의 내용을 추출합니다.
+/// 할 일이 아니거나 체크된 상태라면 `None`을 반환합니다.
+pub fn extract_pending_content(text: &str) -> Option<String> {
+    match extract_todo_content(text) {
+        Some((false, content)) => Some(content),
+        _ => None,
+    }
+}
+
+/// 반복 할 일 정의(예: "물 마시기 every:day")에서 반복 주기를 분리합니다.
+/// `every:` 토큰을 제외한 할 일 내용과 반복 주기를 반환하며, 토큰이 없거나 잘못되었으면 `None`입니다.
+///
+/// 지원 형식: `every:day`, `every:mon,thu`, `every:1st`, `every:1st,15th`
+pub fn parse_recurrence(text: &str) -> Option<(String, Recurrence)> {
+    let mut recurrence = None;
+    let mut words = Vec::new();
+
+    for word in text.split_whitespace() {
+        if recurrence.is_none()
+            && let Some(spec) = word.strip_prefix("every:")
+        {
+            recurrence = Some(parse_recurrence_spec(spec)?);
+        } else {
+            words.push(word);
+        }
+    }
+
+    let content = words.join(" ");
+    if content.is_empty() {
+        return None;
+    }
+    recurrence.map(|r| (content, r))
+}
+
+fn parse_recurrence_spec(spec: &str) -> Option<Recurrence> {
+    let spec = spec.to_lowercase();
+    if spec == "day" || spec == "daily" {
+        return Some(Recurrence::Daily);
+    }
+
+    let parts: Vec<&str> = spec.split(',').filter(|p| !p.is_empty()).collect();
+    if parts.is_empty() {
+        return None;
+    }
+
+    if let Some(days) = parts
+        .iter()
+        .map(|p| p.parse::<chrono::Weekday>().ok())
+        .collect::<Option<Vec<_>>>()
+    {
+        return Some(Recurrence::Weekdays(days));
+    }
+
+    parts
+        .iter()
+        .map(|p| {
+            let digits = p.trim_end_matches(|c: char| c.is_ascii_alphabetic());
+            digits.parse::<u32>().ok().filter(|d| (1..=31).contains(d))
+        })
+        .collect::<Option<Vec<_>>>()
+        .map(Recurrence::MonthDays)
+}
+
 /// 로그 라인 파싱의 메인 진입점입니다. 토큰화와 렌더링을 한번에 수행합니다.
 pub fn parse_log_line(text: &str, theme: &Theme) -> Line<'static> {
     let tokens = tokenize(text);
@@ -340,4 +1058,395 @@ mod tests {
         let toggled_tight = toggle_checkbox(line_tight);
         assert_eq!(toggled_tight, "[12:34] - [x] Tight"); // Normalized to wide
     }
+
+    #[test]
+    fn test_parse_recurrence() {
+        let (content, rec) = parse_recurrence("Take vitamins every:day #health").unwrap();
+        assert_eq!(content, "Take vitamins #health");
+        assert_eq!(rec, Recurrence::Daily);
+
+        let (_, rec) = parse_recurrence("분리수거 every:mon,thu").unwrap();
+        assert_eq!(
+            rec,
+            Recurrence::Weekdays(vec![chrono::Weekday::Mon, chrono::Weekday::Thu])
+        );
+
+        let (_, rec) = parse_recurrence("월세 every:1st,15th").unwrap();
+        assert_eq!(rec, Recurrence::MonthDays(vec![1, 15]));
+
+        assert!(parse_recurrence("No recurrence here").is_none());
+        assert!(parse_recurrence("Bad every:someday").is_none());
+        assert!(parse_recurrence("every:day").is_none());
+    }
+
+    #[test]
+    fn test_done_stamp() {
+        let date = NaiveDate::from_ymd_opt(2025, 1, 10).unwrap();
+        let same_day = date.and_hms_opt(14, 30, 0).unwrap();
+        let next_day = same_day + chrono::Duration::days(1);
+
+        assert_eq!(format_done_stamp(same_day, date), "done:14:30");
+        assert_eq!(format_done_stamp(next_day, date), "done:2025-01-11T14:30");
+
+        let line = "[09:00:00] - [ ] Write report";
+        let done = toggle_checkbox_with_stamp(line, "done:14:30");
+        assert_eq!(done, "[09:00:00] - [x] Write report done:14:30");
+        assert_eq!(parse_done_stamp(&done, date), Some(same_day));
+        assert_eq!(
+            extract_todo_content(&done),
+            Some((true, "Write report".to_string()))
+        );
+
+        let undone = toggle_checkbox_with_stamp(&done, "done:15:00");
+        assert_eq!(undone, line);
+
+        // 완료된 할 일이 아니면 `done:`으로 시작하는 사용자 글을 건드리지 않음
+        let plain = "[09:00:00] deploy done:prod";
+        // 완료된 할 일이라도 시각 형식이 아닌 `done:` 글은 내용으로 남김
+        let checked = "[09:00:00] - [x] deploy done:prod done:14:30";
+        assert_eq!(parse_done_stamp(checked, date), Some(same_day));
+        assert_eq!(
+            extract_todo_content(checked),
+            Some((true, "deploy done:prod".to_string()))
+        );
+        assert_eq!(
+            toggle_checkbox_with_stamp(checked, "done:15:00"),
+            "[09:00:00] - [ ] deploy done:prod"
+        );
+        assert_eq!(strip_done_stamp(plain), plain);
+        assert_eq!(
+            strip_done_stamp("- [ ] deploy done:prod"),
+            "- [ ] deploy done:prod"
+        );
+
+        assert_eq!(
+            parse_done_stamp("- [x] Late done:2025-01-11T14:30", date),
+            Some(next_day)
+        );
+        assert_eq!(parse_entry_time(line, date), date.and_hms_opt(9, 0, 0));
+    }
+
+    #[test]
+    fn test_parse_reminder() {
+        let date = NaiveDate::from_ymd_opt(2025, 1, 10).unwrap();
+        let created = date.and_hms_opt(13, 0, 0).unwrap();
+
+        let line = "[13:00:00] - [ ] call dentist remind:15:30";
+        assert_eq!(parse_reminder(line, created), date.and_hms_opt(15, 30, 0));
+        assert_eq!(
+            parse_reminder("- [ ] stretch remind:+1h15m", created),
+            date.and_hms_opt(14, 15, 0)
+        );
+        assert_eq!(parse_reminder("- [ ] no reminder", created), None);
+
+        let dismissed = mark_reminder_dismissed(line);
+        assert_eq!(dismissed, "[13:00:00] - [ ] call dentist reminded:15:30");
+        assert_eq!(parse_reminder(&dismissed, created), None);
+
+        assert_eq!(parse_duration("45m"), Some(45));
+        assert_eq!(parse_duration("2h"), Some(120));
+        assert_eq!(parse_duration("abc"), None);
+        assert_eq!(parse_duration(""), None);
+    }
+
+    #[test]
+    fn test_focus_entry() {
+        let line = "[10:00:00] Focus: write report #work";
+        assert_eq!(
+            parse_focus_entry(line),
+            Some(FocusEntry::Set("write report #work"))
+        );
+        assert_eq!(tokenize(line)[2], LogToken::Focus);
+        assert_eq!(parse_focus_entry("[10:00:00] Mood: 😊"), None);
+
+        let cleared = format_focus_entry(None);
+        assert_eq!(parse_focus_entry(&cleared), Some(FocusEntry::Cleared));
+        assert_eq!(
+            format_focus_entry(Some("write report")),
+            "Focus: write report"
+        );
+    }
+
+    #[test]
+    fn test_pomodoro_entry() {
+        let started = PomodoroEvent::Started {
+            phase: PomodoroPhase::Work,
+            minutes: 25,
+            tags: vec!["#project".to_string()],
+        };
+        let line = format!("[10:00:00] {}", format_pomodoro_event(&started));
+        assert_eq!(line, "[10:00:00] Pomodoro: 25m started #project");
+        assert_eq!(parse_pomodoro_entry(&line), Some(started));
+        assert_eq!(tokenize(&line)[2], LogToken::Pomodoro);
+
+        assert_eq!(
+            parse_pomodoro_entry("[10:25:00] Pomodoro: completed"),
+            Some(PomodoroEvent::Completed {
+                internal: 0,
+                external: 0
+            })
+        );
+
+        let completed = PomodoroEvent::Completed {
+            internal: 2,
+            external: 1,
+        };
+        assert_eq!(
+            format_pomodoro_event(&completed),
+            "Pomodoro: completed int:2 ext:1"
+        );
+        assert_eq!(
+            parse_pomodoro_entry("[10:25:00] Pomodoro: completed int:2 ext:1"),
+            Some(completed)
+        );
+        assert_eq!(
+            parse_pomodoro_entry("[10:05:00] Pomodoro: stopped"),
+            Some(PomodoroEvent::Stopped)
+        );
+        assert_eq!(parse_pomodoro_entry("[10:05:00] Pomodoro: hello"), None);
+
+        let extended = PomodoroEvent::Extended { minutes: 5 };
+        assert_eq!(format_pomodoro_event(&extended), "Pomodoro: extended +5m");
+        assert_eq!(
+            parse_pomodoro_entry("[10:20:00] Pomodoro: extended +5m"),
+            Some(extended)
+        );
+        assert_eq!(
+            parse_pomodoro_entry("[10:10:00] Pomodoro: paused"),
+            Some(PomodoroEvent::Paused)
+        );
+
+        let long_break = PomodoroEvent::Started {
+            phase: PomodoroPhase::LongBreak,
+            minutes: 15,
+            tags: vec![],
+        };
+        assert_eq!(
+            format_pomodoro_event(&long_break),
+            "Pomodoro: 15m long-break started"
+        );
+        assert_eq!(
+            parse_pomodoro_entry("[11:40:00] Pomodoro: 15m long-break started"),
+            Some(long_break)
+        );
+    }
+
+    #[test]
+    fn test_interruption_entry() {
+        let interruption = Interruption {
+            external: true,
+            text: "팀장님 호출 #work".to_string(),
+        };
+        let line = format!("[10:12:00] {}", format_interruption(&interruption));
+        assert_eq!(line, "[10:12:00] Interruption: external 팀장님 호출 #work");
+        assert_eq!(tokenize(&line)[2], LogToken::Interruption);
+        assert_eq!(parse_interruption_entry(&line), Some(interruption));
+        assert_eq!(
+            parse_interruption_entry("[10:13:00] Interruption: internal 우유 사기"),
+            Some(Interruption {
+                external: false,
+                text: "우유 사기".to_string(),
+            })
+        );
+        assert_eq!(
+            parse_interruption_entry("[10:14:00] Interruption: hmm"),
+            None
+        );
+    }
+
+    #[test]
+    fn test_quiet_hours() {
+        let t = |text| NaiveTime::parse_from_str(text, "%H:%M").unwrap();
+        assert_eq!(
+            parse_time_range("22:00-08:00"),
+            Some((t("22:00"), t("08:00")))
+        );
+        assert_eq!(parse_time_range("22:00"), None);
+
+        let ranges = vec!["22:00-08:00".to_string(), "12:00-13:00".to_string()];
+        assert!(in_time_ranges(&ranges, t("23:30")));
+        assert!(in_time_ranges(&ranges, t("07:59")));
+        assert!(in_time_ranges(&ranges, t("12:30")));
+        assert!(!in_time_ranges(&ranges, t("08:00")));
+        assert!(!in_time_ranges(&ranges, t("15:00")));
+    }
+
+    #[test]
+    fn test_estimate_and_spent() {
+        let line = "[09:00:00] - [ ] write report ~45m #work";
+        assert_eq!(parse_estimate(line), Some(45));
+        assert_eq!(parse_spent(line), None);
+
+        let line = add_spent(line, 50);
+        assert_eq!(line, "[09:00:00] - [ ] write report ~45m #work spent:50m");
+        let line = add_spent(&line, 20);
+        assert_eq!(line, "[09:00:00] - [ ] write report ~45m #work spent:1h10m");
+        assert_eq!(parse_spent(&line), Some(70));
+
+        // 사용 시간이 바뀌어도 할 일 내용(식별자)은 그대로
+        assert_eq!(
+            extract_pending_content(&line),
+            Some("write report ~45m #work".to_string())
+        );
+    }
+
+    #[test]
+    fn test_parse_timer_spec() {
+        assert_eq!(
+            parse_timer_spec("laundry 40m"),
+            Some(("laundry".to_string(), Some(40)))
+        );
+        assert_eq!(
+            parse_timer_spec("standup in 10m"),
+            Some(("standup".to_string(), Some(10)))
+        );
+        assert_eq!(
+            parse_timer_spec("고객 통화"),
+            Some(("고객 통화".to_string(), None))
+        );
+        assert_eq!(
+            parse_timer_spec("1h30m"),
+            Some(("timer".to_string(), Some(90)))
+        );
+        assert_eq!(parse_timer_spec("   "), None);
+    }
+
+    #[test]
+    fn test_parse_checkin() {
+        assert_eq!(
+            parse_checkin("[09:00:00] Mood: 😊 조음 mood:happy energy:5 focus:1"),
+            Some(Checkin {
+                mood_id: Some("happy".to_string()),
+                energy: Some(5),
+                focus: Some(1),
+            })
+        );
+        // 1~5 밖의 점수나 숫자가 아닌 점수는 없는 것으로 봄
+        assert_eq!(
+            parse_checkin("[09:00:00] Mood: 😊 조음 mood:happy energy:0 focus:7"),
+            Some(Checkin {
+                mood_id: Some("happy".to_string()),
+                energy: None,
+                focus: None,
+            })
+        );
+        assert_eq!(
+            parse_checkin("[09:00:00] Mood: 😊 조음 mood:happy energy:high"),
+            Some(Checkin {
+                mood_id: Some("happy".to_string()),
+                energy: None,
+                focus: None,
+            })
+        );
+        // 점수 없이 기분만 남긴 예전 기록
+        assert_eq!(
+            parse_checkin("[09:00:00] Mood: 😊 조음"),
+            Some(Checkin {
+                mood_id: Some("happy".to_string()),
+                energy: None,
+                focus: None,
+            })
+        );
+        assert_eq!(parse_checkin("[09:00:00] 오늘 energy:4 focus:4"), None);
+        assert_eq!(parse_checkin("[09:00:00] - [ ] Mood: 체크인"), None);
+    }
+
+    #[test]
+    fn test_mood_entry() {
+        let mood = MoodConfig {
+            id: "calm".to_string(),
+            emoji: "🌊".to_string(),
+            label: "Calm".to_string(),
+            color: String::new(),
+            valence: Some(1),
+        };
+        let line = format!("[09:00:00] {}", format_mood_entry(&mood, None, None));
+        assert_eq!(line, "[09:00:00] Mood: 🌊 Calm mood:calm");
+        assert_eq!(parse_mood_id(&line), Some("calm".to_string()));
+
+        let line = format!("[13:30:00] {}", format_mood_entry(&mood, Some(4), Some(2)));
+        assert_eq!(line, "[13:30:00] Mood: 🌊 Calm mood:calm energy:4 focus:2");
+        assert_eq!(
+            parse_checkin(&line),
+            Some(Checkin {
+                mood_id: Some("calm".to_string()),
+                energy: Some(4),
+                focus: Some(2),
+            })
+        );
+        assert_eq!(tokenize(&line).last(), Some(&LogToken::MoodId("focus:2")));
+
+        // 식별자가 없는 예전 기록
+        assert_eq!(
+            parse_mood_id("[09:00:00] Mood: 😫 구림"),
+            Some("stressed".to_string())
+        );
+        assert_eq!(parse_mood_id("[09:00:00] Mood: 뭔가 이상함"), None);
+        assert_eq!(parse_mood_id("[09:00:00] Focus: 😊 조음"), None);
+    }
+
+    #[test]
+    fn test_entry_hour_and_tag() {
+        assert_eq!(parse_entry_hour("[14:05:30] 점심 먹음"), Some(14));
+        assert_eq!(parse_entry_hour("[09:00] 짧은 형식"), Some(9));
+        assert_eq!(parse_entry_hour("시간 없음 [10:00:00]"), None);
+
+        let line = "[10:00:00] - [ ] 발표 준비 #Project-X #work";
+        assert!(has_tag(line, "#project-x"));
+        assert!(has_tag(line, "work"));
+        assert!(!has_tag(line, "#wor"));
+        assert!(!has_tag(line, ""));
+    }
+
+    #[test]
+    fn test_tag_completion() {
+        assert_eq!(tag_prefix_at("회의 #wo", 6), Some("#wo"));
+        assert_eq!(tag_prefix_at("회의 #wo 끝", 6), Some("#wo"));
+        assert_eq!(tag_prefix_at("회의 #업", 5), Some("#업"));
+        assert_eq!(tag_prefix_at("회의 #wo", 2), None);
+        assert_eq!(tag_prefix_at("#", 1), Some("#"));
+        assert_eq!(tag_prefix_at("", 0), None);
+        assert_eq!(word_suffix_at("회의 #wo끝 다음", 6), "끝");
+        assert_eq!(word_suffix_at("회의 #wo", 6), "");
+        assert_eq!(word_suffix_at("#업무", 2), "무");
+
+        let tags = vec![
+            ("#work".to_string(), 10),
+            ("#homework".to_string(), 3),
+            ("#Work".to_string(), 2),
+            ("#업무".to_string(), 5),
+        ];
+        // 앞부분 일치가 먼저, 그다음 포함 (대소문자 무시)
+        // 대소문자만 다른 `#Work`는 순위가 높은 `#work`로 한 번만
+        assert_eq!(complete_tag(&tags, "#wo", 6), vec!["#work", "#homework"]);
+        assert_eq!(complete_tag(&tags, "#work", 6), vec!["#homework"]);
+        assert_eq!(complete_tag(&tags, "#WORK", 6), vec!["#homework"]);
+        assert_eq!(complete_tag(&tags, "#업", 6), vec!["#업무"]);
+        assert_eq!(complete_tag(&tags, "#", 2), vec!["#work", "#homework"]);
+    }
+
+    #[test]
+    fn test_hierarchical_tags() {
+        assert_eq!(
+            normalize_tag("#work/sonomemo/bugs").as_deref(),
+            Some("#work/sonomemo/bugs")
+        );
+        assert_eq!(normalize_tag("work//x/").as_deref(), Some("#work/x"));
+        assert_eq!(normalize_tag("#/"), None);
+
+        // 상위 태그로 찾으면 하위 태그도 포함, 이름이 비슷한 다른 태그는 제외
+        let line = "[10:00:00] 버그 수정 #Work/sonomemo/bugs";
+        assert!(has_tag(line, "#work"));
+        assert!(has_tag(line, "#work/sonomemo/"));
+        assert!(has_tag(line, "#work/sonomemo/bugs"));
+        assert!(!has_tag(line, "#wor"));
+        assert!(!has_tag(line, "#work/sono"));
+        assert!(!has_tag("[10:00:00] 운동 #workout", "#work"));
+        assert!(has_tag("[10:00:00] 회의\n  후속 #work/meeting", "#work"));
+
+        assert!(is_tag_query(" #work/sonomemo "));
+        assert!(!is_tag_query("#work 회의"));
+        assert!(!is_tag_query("work"));
+        assert!(!is_tag_query("#"));
+    }
 }
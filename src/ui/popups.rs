@@ -1,47 +1,61 @@
+use super::color_parser::parse_color;
 use super::components::centered_rect;
+use super::parser;
 use crate::app::App;
-use crate::models::Mood;
-use chrono::Local;
+use crate::models::{CheckinStep, PomodoroPhase};
+use chrono::{Datelike, Local};
 use ratatui::{
     Frame,
-    layout::{Constraint, Direction, Layout},
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
+    symbols,
     text::{Line, Span},
-    widgets::{Block, Borders, Clear, List, ListItem, Paragraph},
+    widgets::{
+        Axis, BarChart, Block, Borders, Chart, Clear, Dataset, GraphType, List, ListItem,
+        ListState, Paragraph, Sparkline,
+    },
 };
 
-pub fn render_siren_popup(f: &mut Frame) {
+pub fn render_siren_popup(f: &mut Frame, app: &App) {
+    let alert = &app.config.alert;
+    let mut modifier = Modifier::BOLD;
+    if alert.blink {
+        modifier |= Modifier::RAPID_BLINK;
+    }
     let block = Block::default().borders(Borders::ALL).style(
         Style::default()
             .fg(Color::Red)
             .bg(Color::Black)
-            .add_modifier(Modifier::BOLD | Modifier::RAPID_BLINK),
+            .add_modifier(modifier),
     );
 
     let area = centered_rect(80, 60, f.area());
     f.render_widget(Clear, area);
     f.render_widget(block, area);
 
-    let siren_art = vec![
-        "         _______  TIME'S UP!  _______",
-        "        /       \\            /       \\",
-        "       |  (o)  |   🚨🚨🚨   |  (o)  |",
-        "        \\_______/            \\_______/",
-        "",
-        "      Take a break! Stretch! Drink water!",
-        "      (Input blocked for 5 seconds)",
-    ];
-
     let text_area = Layout::default()
         .direction(Direction::Vertical)
         .constraints([Constraint::Percentage(100)])
         .margin(2)
         .split(area)[0];
 
+    let footer = match app.pomodoro_alert_expiry {
+        Some(expiry) if app.siren_input_blocked() => {
+            let remaining = (expiry - Local::now()).num_seconds() + 1;
+            format!("      (Input blocked for {} seconds)", remaining)
+        }
+        _ => "      (Press any key to dismiss)".to_string(),
+    };
+
     let mut art_spans = Vec::new();
-    for line in siren_art {
+    for line in alert
+        .art
+        .iter()
+        .map(String::as_str)
+        .chain([footer.as_str()])
+    {
         art_spans.push(ListItem::new(Line::from(Span::styled(
-            line,
+            line.to_string(),
             Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
         ))));
     }
@@ -49,51 +63,740 @@ pub fn render_siren_popup(f: &mut Frame) {
     f.render_widget(List::new(art_spans), text_area);
 }
 
+pub fn render_reminder_popup(f: &mut Frame, app: &App) {
+    let Some(reminder) = &app.active_reminder else {
+        return;
+    };
+
+    let block = Block::default()
+        .title(" 🔔 Reminder ")
+        .borders(Borders::ALL)
+        .border_style(
+            Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD),
+        );
+    let area = centered_rect(50, 25, f.area());
+    f.render_widget(Clear, area);
+    f.render_widget(block, area);
+
+    let lines = vec![
+        Line::from(Span::styled(
+            reminder.text.clone(),
+            Style::default().add_modifier(Modifier::BOLD),
+        )),
+        Line::from(format!("⏰ {}", reminder.due.format("%H:%M"))),
+        Line::from(""),
+        Line::from(Span::styled(
+            format!(
+                "[{}] Dismiss  [{}] Snooze {}m",
+                app.config.keybindings.reminder.dismiss.join("/"),
+                app.config.keybindings.reminder.snooze.join("/"),
+                app.config.reminder.snooze_minutes
+            ),
+            Style::default().fg(Color::DarkGray),
+        )),
+    ];
+
+    let inner_area = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Percentage(100)])
+        .margin(2)
+        .split(area)[0];
+
+    f.render_widget(
+        Paragraph::new(lines).wrap(ratatui::widgets::Wrap { trim: true }),
+        inner_area,
+    );
+}
+
+/// 입력 중인 태그의 자동완성 후보를 커서(`anchor`) 바로 위(자리가 없으면 아래)에 띄웁니다.
+pub fn render_tag_suggestions(f: &mut Frame, app: &App, anchor: (u16, u16)) {
+    let screen = f.area();
+    let width = app
+        .tag_suggestions
+        .iter()
+        .map(|tag| Line::from(tag.as_str()).width())
+        .max()
+        .unwrap_or(0) as u16
+        + 4;
+    let width = width.min(screen.width);
+    let height = (app.tag_suggestions.len() as u16 + 2).min(screen.height);
+    let x = anchor.0.min(screen.right().saturating_sub(width));
+    let y = if anchor.1 >= screen.y + height {
+        anchor.1 - height
+    } else {
+        (anchor.1 + 1).min(screen.bottom().saturating_sub(height))
+    };
+    let area = Rect {
+        x,
+        y,
+        width,
+        height,
+    };
+
+    let tag_color = parse_color(&app.config.theme.tag);
+    let items: Vec<ListItem> = app
+        .tag_suggestions
+        .iter()
+        .map(|tag| ListItem::new(tag.as_str()).style(Style::default().fg(tag_color)))
+        .collect();
+    let mut state = ListState::default();
+    state.select(Some(app.tag_suggestion_index));
+
+    f.render_widget(Clear, area);
+    f.render_stateful_widget(
+        List::new(items)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_style(Style::default().fg(Color::DarkGray)),
+            )
+            .highlight_style(Style::default().add_modifier(Modifier::REVERSED)),
+        area,
+        &mut state,
+    );
+}
+
+pub fn render_celebration_popup(f: &mut Frame, app: &App) {
+    let Some(message) = &app.celebration else {
+        return;
+    };
+    // 폭죽 🎉
+    let confetti = [
+        Color::LightRed,
+        Color::Yellow,
+        Color::LightGreen,
+        Color::LightCyan,
+        Color::LightMagenta,
+    ];
+    let burst = |offset: usize| {
+        Line::from(
+            "* . ✦ ° * . ✦ ° * . ✦ °"
+                .split(' ')
+                .enumerate()
+                .flat_map(|(i, piece)| {
+                    [
+                        Span::styled(
+                            piece,
+                            Style::default().fg(confetti[(i + offset) % confetti.len()]),
+                        ),
+                        Span::raw(" "),
+                    ]
+                })
+                .collect::<Vec<_>>(),
+        )
+    };
+
+    let mut lines = vec![
+        burst(0),
+        Line::from(""),
+        Line::from("🎉 🎉 🎉").style(Style::default().add_modifier(Modifier::BOLD)),
+    ];
+    lines.push(Line::from(""));
+    for text in message.lines() {
+        lines.push(
+            Line::from(text.to_string()).style(Style::default().add_modifier(Modifier::BOLD)),
+        );
+    }
+    lines.push(Line::from(""));
+    lines.push(burst(2));
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled(
+        "Press any key",
+        Style::default().add_modifier(Modifier::DIM),
+    )));
+
+    let height = lines.len() as u16 + 2;
+    let area = f.area();
+    let area = Rect {
+        x: area.x + area.width.saturating_sub(50) / 2,
+        y: area.y + area.height.saturating_sub(height) / 2,
+        width: area.width.min(50),
+        height: height.min(area.height),
+    };
+    f.render_widget(Clear, area);
+    f.render_widget(
+        Paragraph::new(lines)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_style(Style::default().fg(Color::Yellow)),
+            )
+            .alignment(Alignment::Center),
+        area,
+    );
+}
+
+/// 팝업 안내 문구에 보여줄 대표 키 (바인딩 목록의 첫 번째)
+fn key_hint(bindings: &[String]) -> &str {
+    bindings.first().map_or("", String::as_str)
+}
+
 pub fn render_activity_popup(f: &mut Frame, app: &App) {
+    let (start, end) = app.activity_range();
+    let metric = app.activity_metric;
+    let keys = &app.config.keybindings.activity;
+    let metric_keys = match (keys.metrics.first(), keys.metrics.last()) {
+        (Some(first), Some(last)) if keys.metrics.len() > 1 => format!("/{}-{}", first, last),
+        (Some(only), _) => format!("/{}", only),
+        _ => String::new(),
+    };
     let block = Block::default()
-        .title(" 🌱 Activity Graph (Last 2 Weeks) ")
+        .title(format!(
+            " 🌱 Activity: {} {} ~ {}  [{}{}] Metric  [{}/{}] Year  [{}/{}] Week  [{}/{}] Day  [{}] Open ",
+            metric.label(),
+            start.format("%Y-%m-%d"),
+            end.format("%Y-%m-%d"),
+            key_hint(&keys.next_metric),
+            metric_keys,
+            key_hint(&keys.prev_year),
+            key_hint(&keys.next_year),
+            key_hint(&keys.prev_week),
+            key_hint(&keys.next_week),
+            key_hint(&keys.prev_day),
+            key_hint(&keys.next_day),
+            key_hint(&keys.open_day)
+        ))
         .borders(Borders::ALL);
-    let area = centered_rect(60, 40, f.area());
+    let area = centered_rect(90, 60, f.area());
+    f.render_widget(Clear, area);
+    f.render_widget(block, area);
+
+    let inner_area = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(1),
+            Constraint::Length(7),
+            Constraint::Length(1),
+            Constraint::Length(1),
+            Constraint::Length(1),
+            Constraint::Length(1),
+            Constraint::Min(0),
+            Constraint::Length(1),
+            Constraint::Length(1),
+        ])
+        .margin(2)
+        .split(area);
+
+    let count_of = |date: chrono::NaiveDate| {
+        app.activity_data
+            .get(&date)
+            .map_or(0, |day| day.value(metric))
+    };
+
+    // 7행(월~일) × 53열(주) 잔디밭
+    const LABEL_WIDTH: usize = 4;
+    let weeks = ((end - start).num_days() / 7 + 1) as usize;
+    let cell_width = if inner_area[1].width as usize >= LABEL_WIDTH + weeks * 2 {
+        2
+    } else {
+        1
+    };
+    let mut colors: Vec<Color> = app
+        .config
+        .theme
+        .heatmap
+        .iter()
+        .map(|c| parse_color(c))
+        .collect();
+    if colors.len() < 2 {
+        colors = vec![Color::DarkGray, Color::Green];
+    }
+    let days: Vec<(chrono::NaiveDate, usize)> = start
+        .iter_days()
+        .take_while(|date| *date <= end)
+        .map(|date| (date, count_of(date)))
+        .collect();
+    let max = days.iter().map(|(_, count)| *count).max().unwrap_or(0);
+    // 기록이 없으면 0단계, 나머지는 최댓값 기준으로 1 ~ (색상 수 - 1)단계
+    let level = |count: usize| {
+        if count == 0 {
+            0
+        } else {
+            1 + (count - 1) * (colors.len() - 1) / max
+        }
+    };
+
+    let mut month_row = vec![' '; LABEL_WIDTH + weeks * cell_width];
+    let mut last_label_end = 0;
+    for week in 0..weeks {
+        let monday = start + chrono::Duration::weeks(week as i64);
+        let previous = monday - chrono::Duration::weeks(1);
+        let x = LABEL_WIDTH + week * cell_width;
+        if (week == 0 || monday.month() != previous.month()) && x >= last_label_end {
+            for (i, c) in monday.format("%b").to_string().chars().enumerate() {
+                if let Some(slot) = month_row.get_mut(x + i) {
+                    *slot = c;
+                }
+            }
+            last_label_end = x + 4;
+        }
+    }
+    f.render_widget(
+        Paragraph::new(month_row.into_iter().collect::<String>())
+            .style(Style::default().fg(Color::DarkGray)),
+        inner_area[0],
+    );
+
+    let mut grid = Vec::new();
+    for (row, label) in ["Mon", "", "Wed", "", "Fri", "", "Sun"].iter().enumerate() {
+        let mut spans = vec![Span::styled(
+            format!("{:<width$}", label, width = LABEL_WIDTH),
+            Style::default().fg(Color::DarkGray),
+        )];
+        for week in 0..weeks {
+            let Some((date, count)) = days.get(week * 7 + row) else {
+                spans.push(Span::raw(" ".repeat(cell_width)));
+                continue;
+            };
+            let mut style = Style::default().fg(colors[level(*count)]);
+            if *date == app.activity_cursor {
+                style = style.bg(Color::White);
+            }
+            spans.push(Span::styled("■", style));
+            if cell_width == 2 {
+                spans.push(Span::raw(" "));
+            }
+        }
+        grid.push(Line::from(spans));
+    }
+    f.render_widget(Paragraph::new(grid), inner_area[1]);
+
+    // 커서가 가리키는 날과 기간 합계
+    let cursor_count = count_of(app.activity_cursor);
+    f.render_widget(
+        Paragraph::new(format!(
+            "📅 {}: {} {}",
+            app.activity_cursor.format("%Y-%m-%d (%a)"),
+            cursor_count,
+            metric.label()
+        ))
+        .style(Style::default().add_modifier(Modifier::BOLD)),
+        inner_area[3],
+    );
+    let total: usize = days.iter().map(|(_, count)| count).sum();
+    let active = days.iter().filter(|(_, count)| *count > 0).count();
+    let mut legend = vec![Span::styled(
+        format!(
+            "🌱 {} {} · {} active days   Less ",
+            total,
+            metric.label(),
+            active
+        ),
+        Style::default().fg(Color::DarkGray),
+    )];
+    for color in &colors {
+        legend.push(Span::styled("■ ", Style::default().fg(*color)));
+    }
+    legend.push(Span::styled("More", Style::default().fg(Color::DarkGray)));
+    f.render_widget(Paragraph::new(Line::from(legend)), inner_area[4]);
+
+    // 연속 기록 (현재 / 최장)
+    f.render_widget(
+        Paragraph::new(format!(
+            "🔥 Logging streak {} days (longest {}) · ✅ Todo streak {} days (longest {})",
+            app.log_streak.current,
+            app.log_streak.longest,
+            app.todo_streak.current,
+            app.todo_streak.longest
+        ))
+        .style(Style::default().fg(Color::LightRed)),
+        inner_area[5],
+    );
+
+    // 할 일 사이클 타임 (생성 → 완료)
+    let stats = &app.todo_cycle_stats;
+    let cycle_text = match stats.average_minutes() {
+        Some(avg) => format!(
+            "✅ {} todos done · avg {} · longest {}",
+            stats.completed,
+            parser::format_duration(avg),
+            parser::format_duration(stats.longest_minutes)
+        ),
+        None => "✅ No completed todos with timestamps yet".to_string(),
+    };
+    f.render_widget(
+        Paragraph::new(cycle_text).style(Style::default().fg(Color::DarkGray)),
+        inner_area[7],
+    );
+
+    // 예상 시간(~45m) 대비 실제 사용 시간(spent:) 정확도
+    let estimate_text = match stats.estimate_ratio() {
+        Some(ratio) => format!(
+            "🎯 {} estimated todos · actual/estimate {:.1}x · {}/{} within ±25%",
+            stats.estimated, ratio, stats.accurate, stats.estimated
+        ),
+        None => "🎯 No done todos with both ~estimate and spent: yet".to_string(),
+    };
+    f.render_widget(
+        Paragraph::new(estimate_text).style(Style::default().fg(Color::DarkGray)),
+        inner_area[8],
+    );
+}
+
+pub fn render_pomodoro_stats_popup(f: &mut Frame, app: &App) {
+    let block = Block::default()
+        .title(" 🍅 Focus Stats (Last 2 Weeks) ")
+        .borders(Borders::ALL);
+    let area = centered_rect(60, 70, f.area());
     f.render_widget(Clear, area);
     f.render_widget(block, area);
 
+    let stats = &app.pomodoro_stats;
     let today = Local::now().date_naive();
     let mut items = Vec::new();
 
     for i in 0..14 {
         let date = today - chrono::Duration::days(i);
-        let date_str = date.format("%Y-%m-%d").to_string();
-        let count = app.activity_data.get(&date_str).cloned().unwrap_or(0);
+        let (sessions, minutes) = stats.daily.get(&date).cloned().unwrap_or((0, 0));
 
-        let bar_len = count.min(20); // 최대 20칸
+        let bar_len = sessions.min(20); // 최대 20칸
         let bar: String = "■".repeat(bar_len);
 
-        let color = if count == 0 {
+        let color = if sessions == 0 {
             Color::DarkGray
-        } else if count < 5 {
-            Color::Green
+        } else if sessions < 4 {
+            Color::Red
         } else {
-            Color::LightGreen
+            Color::LightRed
         };
 
         items.push(ListItem::new(Line::from(vec![
-            Span::raw(format!("{} : {:3} logs ", date_str, count)),
+            Span::raw(format!(
+                "{} : {:2} 🍅 {:>6} ",
+                date.format("%Y-%m-%d"),
+                sessions,
+                parser::format_duration(minutes)
+            )),
             Span::styled(bar, Style::default().fg(color)),
         ])));
     }
 
+    items.push(ListItem::new(""));
+    if stats.tag_minutes.is_empty() {
+        items.push(ListItem::new(Span::styled(
+            "🏷️ No tagged focus sessions yet (e.g. \"25 #project\")",
+            Style::default().fg(Color::DarkGray),
+        )));
+    }
+    for (tag, minutes) in stats.tag_minutes.iter().take(5) {
+        let bar: String = "■".repeat((*minutes / 25).clamp(1, 20) as usize);
+        items.push(ListItem::new(Line::from(vec![
+            Span::raw(format!(
+                "{:<16} {:>6} ",
+                tag,
+                parser::format_duration(*minutes)
+            )),
+            Span::styled(bar, Style::default().fg(Color::Yellow)),
+        ])));
+    }
+
     let inner_area = Layout::default()
         .direction(Direction::Vertical)
-        .constraints([Constraint::Percentage(100)])
+        .constraints([Constraint::Min(1), Constraint::Length(1)])
         .margin(2)
-        .split(area)[0];
+        .split(area);
+
+    f.render_widget(List::new(items), inner_area[0]);
+
+    // 완료율 및 세션당 평균 방해 횟수
+    let summary = match (stats.completion_rate(), stats.average_interruptions()) {
+        (Some(rate), Some(avg)) => format!(
+            "✅ {}/{} sessions completed ({:.0}%) · ⚡ {:.1} interruptions/session",
+            stats.completed,
+            stats.started,
+            rate * 100.0,
+            avg
+        ),
+        (Some(rate), None) => format!(
+            "✅ {}/{} sessions completed ({:.0}%)",
+            stats.completed,
+            stats.started,
+            rate * 100.0
+        ),
+        _ => "✅ No focus sessions recorded yet".to_string(),
+    };
+    f.render_widget(
+        Paragraph::new(summary).style(Style::default().fg(Color::DarkGray)),
+        inner_area[1],
+    );
+}
+
+pub fn render_mood_trend_popup(f: &mut Frame, app: &App) {
+    let block = Block::default()
+        .title(" 🎭 Mood Trend ")
+        .borders(Borders::ALL);
+    let area = centered_rect(80, 80, f.area());
+    f.render_widget(Clear, area);
+    f.render_widget(block, area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Min(8),
+            Constraint::Length(3),
+            Constraint::Length(3),
+            Constraint::Length(7),
+        ])
+        .margin(1)
+        .split(area);
+
+    let stats = &app.mood_stats;
+    let today = Local::now().date_naive();
+    // 최근 8주 (화면이 좁으면 그만큼 줄임)
+    let span = (chunks[0].width as i64 - 8).clamp(7, 56);
+    let start = today - chrono::Duration::days(span - 1);
+    let window: Vec<Option<&crate::models::MoodDay>> = (0..span)
+        .map(|i| stats.days.get(&(start + chrono::Duration::days(i))))
+        .collect();
+
+    // 하루 평균 기분 점수 꺾은선
+    let points: Vec<(f64, f64)> = window
+        .iter()
+        .enumerate()
+        .filter_map(|(i, day)| Some((i as f64, day.and_then(|d| d.mood())?)))
+        .collect();
+    let valences: Vec<i32> = app.config.moods.iter().filter_map(|m| m.valence).collect();
+    let low = valences.iter().min().copied().unwrap_or(-2).min(0) as f64;
+    let high = valences.iter().max().copied().unwrap_or(2).max(0) as f64;
+
+    let chart = Chart::new(vec![
+        Dataset::default()
+            .name("mood")
+            .marker(symbols::Marker::Braille)
+            .graph_type(GraphType::Line)
+            .style(Style::default().fg(Color::Yellow))
+            .data(&points),
+    ])
+    .block(Block::default().title(" Daily mood (valence) "))
+    .x_axis(
+        Axis::default()
+            .style(Style::default().fg(Color::DarkGray))
+            .bounds([0.0, (span - 1) as f64])
+            .labels(vec![
+                Span::raw(start.format("%m/%d").to_string()),
+                Span::raw(today.format("%m/%d").to_string()),
+            ]),
+    )
+    .y_axis(
+        Axis::default()
+            .style(Style::default().fg(Color::DarkGray))
+            .bounds([low, high])
+            .labels(vec![
+                Span::raw(format!("{:+}", low)),
+                Span::raw("0"),
+                Span::raw(format!("{:+}", high)),
+            ]),
+    );
+    if points.is_empty() {
+        f.render_widget(
+            Paragraph::new("No check-ins with a mood score yet (press [c] to check in)")
+                .style(Style::default().fg(Color::DarkGray)),
+            chunks[0],
+        );
+    } else {
+        f.render_widget(chart, chunks[0]);
+    }
+
+    // 에너지/집중도 (기록 없는 날은 0)
+    let rating = |value: fn(&crate::models::MoodDay) -> Option<f64>| -> Vec<u64> {
+        window
+            .iter()
+            .map(|day| day.and_then(value).map_or(0, |v| v.round() as u64))
+            .collect()
+    };
+    let energy = rating(crate::models::MoodDay::energy);
+    let focus = rating(crate::models::MoodDay::focus);
+    f.render_widget(
+        Sparkline::default()
+            .block(Block::default().title(" ⚡ Energy (1-5) "))
+            .data(&energy)
+            .max(5)
+            .style(Style::default().fg(Color::LightGreen)),
+        chunks[1],
+    );
+    f.render_widget(
+        Sparkline::default()
+            .block(Block::default().title(" 🧠 Focus (1-5) "))
+            .data(&focus)
+            .max(5)
+            .style(Style::default().fg(Color::Cyan)),
+        chunks[2],
+    );
+
+    // 기분과 활동량의 상관관계, 요일별 평균 기분
+    let correlation = |label: &str, metric: fn(&crate::models::MoodDay) -> f64| {
+        let text = match stats.mood_correlation(metric) {
+            Some((r, n)) => {
+                let strength = match r.abs() {
+                    x if x < 0.1 => "none",
+                    x if x < 0.3 => "weak",
+                    x if x < 0.5 => "moderate",
+                    _ => "strong",
+                };
+                format!("r = {:+.2} ({}, {} days)", r, strength, n)
+            }
+            None => "not enough data".to_string(),
+        };
+        Line::from(vec![
+            Span::raw(format!("{:<22}", label)),
+            Span::styled(text, Style::default().fg(Color::Gray)),
+        ])
+    };
+    let mut lines = vec![
+        correlation("Mood vs 📝 entries", |d| d.entries as f64),
+        correlation("Mood vs ✅ todos done", |d| d.todos_done as f64),
+        correlation("Mood vs 🍅 pomodoros", |d| d.pomodoros as f64),
+        Line::from(""),
+    ];
+    let mut weekdays = vec![Span::raw(format!("{:<22}", "Mood by weekday"))];
+    for (name, mood) in ["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"]
+        .iter()
+        .zip(stats.weekday_moods())
+    {
+        let (text, color) = match mood {
+            Some(m) if m > 0.0 => (format!("{} {:+.1}  ", name, m), Color::Green),
+            Some(m) if m < 0.0 => (format!("{} {:+.1}  ", name, m), Color::Red),
+            Some(m) => (format!("{} {:+.1}  ", name, m), Color::Gray),
+            None => (format!("{}  -    ", name), Color::DarkGray),
+        };
+        weekdays.push(Span::styled(text, Style::default().fg(color)));
+    }
+    lines.push(Line::from(weekdays));
+    f.render_widget(Paragraph::new(lines), chunks[3]);
+}
+
+pub fn render_hour_popup(f: &mut Frame, app: &App) {
+    let metric = app.hour_metric;
+    let keys = &app.config.keybindings.hour;
+    let block = Block::default()
+        .title(format!(
+            " 🕒 When: {}  [{}] Metric  [type + {}] #tag filter  [{}] Close ",
+            metric.label(),
+            key_hint(&keys.next_metric),
+            key_hint(&keys.apply),
+            key_hint(&keys.close)
+        ))
+        .borders(Borders::ALL);
+    let area = centered_rect(80, 80, f.area());
+    f.render_widget(Clear, area);
+    f.render_widget(block, area);
 
-    f.render_widget(List::new(items), inner_area);
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(1),
+            Constraint::Length(1),
+            Constraint::Min(6),
+            Constraint::Length(1),
+            Constraint::Length(1),
+            Constraint::Length(7),
+        ])
+        .margin(1)
+        .split(area);
+
+    let filter = if app.hour_tag_filter.is_empty() {
+        Span::styled(
+            "🏷️ all entries (type a tag to filter)",
+            Style::default().fg(Color::DarkGray),
+        )
+    } else {
+        Span::styled(
+            format!("🏷️ {} _", app.hour_tag_filter),
+            Style::default().fg(parse_color(&app.config.theme.tag)),
+        )
+    };
+    f.render_widget(Paragraph::new(Line::from(filter)), chunks[0]);
+
+    // 시간대별 막대 (요일 합계), 아래 격자와 칸을 맞춤
+    const LABEL_WIDTH: usize = 4;
+    let cell_width = if chunks[5].width as usize >= LABEL_WIDTH + 24 * 3 {
+        3
+    } else {
+        2
+    };
+    let hours = app.hour_stats.by_hour(metric);
+    let labels: Vec<String> = (0..24).map(|hour| hour.to_string()).collect();
+    let data: Vec<(&str, u64)> = labels
+        .iter()
+        .zip(hours)
+        .map(|(label, count)| (label.as_str(), count as u64))
+        .collect();
+    let bar_area = Rect {
+        x: chunks[2].x + LABEL_WIDTH as u16,
+        width: chunks[2].width.saturating_sub(LABEL_WIDTH as u16),
+        ..chunks[2]
+    };
+    f.render_widget(
+        BarChart::default()
+            .data(&data)
+            .bar_width(cell_width as u16 - 1)
+            .bar_gap(1)
+            .bar_style(Style::default().fg(Color::LightBlue))
+            .value_style(Style::default().fg(Color::Black).bg(Color::LightBlue)),
+        bar_area,
+    );
+
+    // 요일 × 시간 격자
+    f.render_widget(
+        Paragraph::new("Weekday × hour").style(Style::default().add_modifier(Modifier::BOLD)),
+        chunks[3],
+    );
+    let header: String = std::iter::once(" ".repeat(LABEL_WIDTH))
+        .chain((0..24).map(|hour| {
+            if hour % 3 == 0 {
+                format!("{:<width$}", hour, width = cell_width)
+            } else {
+                " ".repeat(cell_width)
+            }
+        }))
+        .collect();
+    f.render_widget(
+        Paragraph::new(header).style(Style::default().fg(Color::DarkGray)),
+        chunks[4],
+    );
+
+    let grid = app.hour_stats.grid(metric);
+    let max = grid.iter().flatten().copied().max().unwrap_or(0);
+    let mut colors: Vec<Color> = app
+        .config
+        .theme
+        .heatmap
+        .iter()
+        .map(|c| parse_color(c))
+        .collect();
+    if colors.len() < 2 {
+        colors = vec![Color::DarkGray, Color::Green];
+    }
+    let rows: Vec<Line> = ["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"]
+        .iter()
+        .zip(grid)
+        .map(|(name, row)| {
+            let mut spans = vec![Span::styled(
+                format!("{:<width$}", name, width = LABEL_WIDTH),
+                Style::default().fg(Color::DarkGray),
+            )];
+            for count in row {
+                let level = if *count == 0 {
+                    0
+                } else {
+                    1 + (count - 1) * (colors.len() - 1) / max
+                };
+                spans.push(Span::styled(
+                    format!("{:<width$}", "■".repeat(cell_width - 1), width = cell_width),
+                    Style::default().fg(colors[level]),
+                ));
+            }
+            Line::from(spans)
+        })
+        .collect();
+    f.render_widget(Paragraph::new(rows), chunks[5]);
 }
 
 pub fn render_pomodoro_popup(f: &mut Frame, app: &App) {
     let block = Block::default()
-        .title(" 🍅 Set Timer (Minutes) ")
+        .title(" 🍅 Set Timer (Minutes #tag) ")
         .borders(Borders::ALL);
     let area = centered_rect(40, 20, f.area());
     f.render_widget(Clear, area);
@@ -110,7 +813,241 @@ pub fn render_pomodoro_popup(f: &mut Frame, app: &App) {
     f.render_widget(text, input_area);
 }
 
+pub fn render_pomodoro_next_popup(f: &mut Frame, app: &App) {
+    let Some(next) = app.pomodoro_next else {
+        return;
+    };
+    let minutes = match next {
+        PomodoroPhase::Work => app.config.pomodoro.work_minutes,
+        PomodoroPhase::ShortBreak => app.config.pomodoro.short_break_minutes,
+        PomodoroPhase::LongBreak => app.config.pomodoro.long_break_minutes,
+    };
+
+    let block = Block::default()
+        .title(" 🍅 Next Phase ")
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Yellow));
+    let area = centered_rect(40, 20, f.area());
+    f.render_widget(Clear, area);
+    f.render_widget(block, area);
+
+    let session = match next {
+        PomodoroPhase::Work => app.pomodoro_session + 1,
+        _ => app.pomodoro_session,
+    };
+    let lines = vec![
+        Line::from(format!(
+            "{} {} #{} ({}m)",
+            next.icon(),
+            next.label(),
+            session,
+            minutes
+        )),
+        Line::from(""),
+        Line::from(Span::styled(
+            "Start now? (Y/n)",
+            Style::default().fg(Color::DarkGray),
+        )),
+    ];
+
+    let inner_area = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Percentage(100)])
+        .margin(2)
+        .split(area)[0];
+
+    f.render_widget(Paragraph::new(lines), inner_area);
+}
+
+pub fn render_pomodoro_cancel_popup(f: &mut Frame, app: &App) {
+    let block = Block::default()
+        .title(" 🍅 Cancel Timer ")
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::LightRed));
+    let area = centered_rect(40, 20, f.area());
+    f.render_widget(Clear, area);
+    f.render_widget(block, area);
+
+    let remaining = app.pomodoro_remaining().unwrap_or_default();
+    let lines = vec![
+        Line::from(format!(
+            "{} {} - {:02}:{:02} left",
+            app.pomodoro_phase.icon(),
+            app.pomodoro_phase.label(),
+            remaining.num_minutes(),
+            remaining.num_seconds() % 60
+        )),
+        Line::from(""),
+        Line::from(Span::styled(
+            "Really stop this timer? (y/n)",
+            Style::default().fg(Color::DarkGray),
+        )),
+    ];
+
+    let inner_area = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Percentage(100)])
+        .margin(2)
+        .split(area)[0];
+
+    f.render_widget(Paragraph::new(lines), inner_area);
+}
+
+pub fn render_interruption_popup(f: &mut Frame, app: &App) {
+    let bindings = &app.config.keybindings.interruption;
+    let block = Block::default()
+        .title(format!(
+            " ⚡ Interruption ({}: 💭 internal / 📞 external) ",
+            bindings.toggle_kind.join("/")
+        ))
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::LightRed));
+    let area = centered_rect(60, 20, f.area());
+    f.render_widget(Clear, area);
+    f.render_widget(block, area);
+
+    let kind = if app.interruption_external {
+        "📞 external"
+    } else {
+        "💭 internal"
+    };
+    let lines = vec![
+        Line::from(vec![
+            Span::styled(
+                format!("[{}] ", kind),
+                Style::default()
+                    .fg(Color::Yellow)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::raw(format!("{}_", app.interruption_input)),
+        ]),
+        Line::from(""),
+        Line::from(Span::styled(
+            format!(
+                "{}: park it and keep focusing  {}: cancel",
+                bindings.submit.join("/"),
+                bindings.cancel.join("/")
+            ),
+            Style::default().fg(Color::DarkGray),
+        )),
+    ];
+
+    let inner_area = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Percentage(100)])
+        .margin(2)
+        .split(area)[0];
+
+    f.render_widget(Paragraph::new(lines), inner_area);
+}
+
+pub fn render_timers_popup(f: &mut Frame, app: &mut App) {
+    let block = Block::default()
+        .title(format!(
+            " ⏲ Timers - {}: add  {}: stop  {}: close ",
+            app.config.keybindings.timers.add.join("/"),
+            app.config.keybindings.timers.remove.join("/"),
+            app.config.keybindings.timers.close.join("/")
+        ))
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Cyan));
+    let area = centered_rect(50, 40, f.area());
+    f.render_widget(Clear, area);
+    f.render_widget(block, area);
+
+    let now = Local::now();
+    let mut items: Vec<ListItem> = app
+        .timers
+        .iter()
+        .map(|timer| {
+            let shown = timer.display_duration(now);
+            let kind = if timer.end.is_some() {
+                "left"
+            } else {
+                "elapsed"
+            };
+            ListItem::new(format!(
+                "{} {:<20} {:02}:{:02}:{:02} {}",
+                timer.icon(),
+                timer.name,
+                shown.num_hours(),
+                shown.num_minutes() % 60,
+                shown.num_seconds() % 60,
+                kind
+            ))
+        })
+        .collect();
+    if items.is_empty() {
+        items.push(ListItem::new(Span::styled(
+            "No timers running",
+            Style::default().fg(Color::DarkGray),
+        )));
+    }
+
+    let inner_area = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Min(1),
+            Constraint::Length(1),
+            Constraint::Length(1),
+        ])
+        .margin(1)
+        .split(area);
+
+    let list = List::new(items)
+        .highlight_symbol(">> ")
+        .highlight_style(Style::default().fg(Color::Yellow));
+    f.render_stateful_widget(list, inner_area[0], &mut app.timers_state);
+
+    f.render_widget(
+        Paragraph::new("e.g. \"laundry 40m\", \"standup in 10m\", \"call\" (stopwatch)")
+            .style(Style::default().fg(Color::DarkGray)),
+        inner_area[1],
+    );
+    f.render_widget(
+        Paragraph::new(format!("> {}_", app.timer_input)).style(Style::default().fg(Color::Yellow)),
+        inner_area[2],
+    );
+}
+
+pub fn render_parking_lot_popup(f: &mut Frame, app: &mut App) {
+    let block = Block::default()
+        .title(format!(
+            " 🅿️ Parking Lot - {}: to todo  {}: drop  {}: done ",
+            app.config.keybindings.popup.confirm.join("/"),
+            app.config.keybindings.popup.discard.join("/"),
+            app.config.keybindings.popup.cancel.join("/")
+        ))
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::LightGreen));
+    let area = centered_rect(60, 40, f.area());
+    f.render_widget(Clear, area);
+    f.render_widget(block, area);
+
+    let items: Vec<ListItem> = app
+        .parking_lot
+        .iter()
+        .map(|i| ListItem::new(format!("{} {}", i.icon(), i.text)))
+        .collect();
+
+    let inner_area = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Percentage(100)])
+        .margin(1)
+        .split(area)[0];
+
+    let list = List::new(items)
+        .highlight_symbol(">> ")
+        .highlight_style(Style::default().fg(Color::Yellow));
+
+    f.render_stateful_widget(list, inner_area, &mut app.parking_lot_state);
+}
+
 pub fn render_mood_popup(f: &mut Frame, app: &mut App) {
+    if app.checkin_step != CheckinStep::Mood {
+        render_checkin_rating(f, app);
+        return;
+    }
     let block = Block::default()
         .title(" 기분이가 좀 어떠세여? ")
         .borders(Borders::ALL);
@@ -118,8 +1055,20 @@ pub fn render_mood_popup(f: &mut Frame, app: &mut App) {
     f.render_widget(Clear, area);
     f.render_widget(block, area);
 
-    let moods = Mood::all();
-    let items: Vec<ListItem> = moods.iter().map(|m| ListItem::new(m.as_str())).collect();
+    let items: Vec<ListItem> = app
+        .config
+        .moods
+        .iter()
+        .map(|m| {
+            let color = if m.color.is_empty() {
+                &app.config.theme.mood
+            } else {
+                &m.color
+            };
+            ListItem::new(format!("{} {}", m.emoji, m.label))
+                .style(Style::default().fg(parse_color(color)))
+        })
+        .collect();
 
     let popup_layout = Layout::default()
         .direction(Direction::Vertical)
@@ -134,6 +1083,63 @@ pub fn render_mood_popup(f: &mut Frame, app: &mut App) {
     f.render_stateful_widget(list, popup_layout[0], &mut app.mood_list_state);
 }
 
+/// 체크인의 에너지/집중도 단계 (1-5 척도)
+fn render_checkin_rating(f: &mut Frame, app: &App) {
+    let popup = &app.config.keybindings.popup;
+    let (title, value) = match app.checkin_step {
+        CheckinStep::Energy => (" ⚡ 에너지는 어때요? (1-5) ", app.checkin_energy),
+        _ => (" 🧠 집중은 잘 돼요? (1-5) ", app.checkin_focus),
+    };
+    let mood = app
+        .mood_list_state
+        .selected()
+        .and_then(|i| app.config.moods.get(i))
+        .map(|m| format!("{} {}", m.emoji, m.label))
+        .unwrap_or_default();
+
+    let scale: Vec<Span> = (1..=5u8)
+        .flat_map(|n| {
+            let style = if n == value {
+                Style::default()
+                    .fg(Color::Black)
+                    .bg(Color::Yellow)
+                    .add_modifier(Modifier::BOLD)
+            } else {
+                Style::default().fg(Color::Gray)
+            };
+            [Span::styled(format!(" {n} "), style), Span::raw(" ")]
+        })
+        .collect();
+
+    let lines = vec![
+        Line::from(mood),
+        Line::from(""),
+        Line::from(scale),
+        Line::from(""),
+        Line::from(Span::styled(
+            format!(
+                "[{}] 선택  [{}/{}] 조정  [{}] 다음  [{}] 건너뛰기",
+                popup.ratings.join("/"),
+                popup.up.join("/"),
+                popup.down.join("/"),
+                popup.confirm.join("/"),
+                popup.cancel.join("/")
+            ),
+            Style::default().add_modifier(Modifier::DIM),
+        )),
+    ];
+
+    let block = Block::default().title(title).borders(Borders::ALL);
+    let area = centered_rect(60, 20, f.area());
+    f.render_widget(Clear, area);
+    f.render_widget(
+        Paragraph::new(lines)
+            .block(block)
+            .alignment(Alignment::Center),
+        area,
+    );
+}
+
 pub fn render_todo_popup(f: &mut Frame, app: &mut App) {
     let title = format!(
         " 지난 할 일이 {}개 남았습니다. 오늘로 가져올까요? (Y/n) ",
@@ -166,16 +1172,41 @@ pub fn render_todo_popup(f: &mut Frame, app: &mut App) {
 
 pub fn render_tag_popup(f: &mut Frame, app: &mut App) {
     let block = Block::default()
-        .title(" 태그를 선택하세요 (Enter: 검색, Esc: 닫기) ")
+        .title(format!(
+            " 태그 ({}: 하위 포함 검색, {}/{}: 접기/펼치기) ",
+            key_hint(&app.config.keybindings.popup.confirm),
+            key_hint(&app.config.keybindings.tags.collapse),
+            key_hint(&app.config.keybindings.tags.expand)
+        ))
         .borders(Borders::ALL);
     let area = centered_rect(50, 60, f.area());
     f.render_widget(Clear, area);
     f.render_widget(block, area);
 
+    let tag_color = parse_color(&app.config.theme.tag);
     let items: Vec<ListItem> = app
-        .tags
-        .iter()
-        .map(|(tag, count)| ListItem::new(format!("{} ({})", tag, count)))
+        .visible_tags()
+        .into_iter()
+        .map(|node| {
+            let marker = match (node.has_children, app.collapsed_tags.contains(&node.path)) {
+                (false, _) => "  ",
+                (true, false) => "▾ ",
+                (true, true) => "▸ ",
+            };
+            let mut spans = vec![
+                Span::raw(format!("{}{}", "  ".repeat(node.depth), marker)),
+                Span::styled(node.name.clone(), Style::default().fg(tag_color)),
+                Span::raw(format!(" ({})", node.total)),
+            ];
+            // 하위 합계와 별개로 상위 태그 자체가 붙은 횟수
+            if node.has_children && node.count > 0 {
+                spans.push(Span::styled(
+                    format!(" · 직접 {}", node.count),
+                    Style::default().add_modifier(Modifier::DIM),
+                ));
+            }
+            ListItem::new(Line::from(spans))
+        })
         .collect();
 
     let popup_layout = Layout::default()